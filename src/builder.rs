@@ -0,0 +1,314 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use reqwest::{Client, Proxy, Url};
+
+use crate::{
+    cache::{CacheConfig, DiskCache, MemoryCache},
+    keys::{KeyPool, PrivateKey, PRIVATE_KEY_ENV},
+    language::Language,
+    ratelimit::{RateLimit, RateLimiter},
+    retry::RetryPolicy,
+    Error, XIVAPIClient,
+};
+
+/// The URL used when no base URL is configured.
+pub(crate) const DEFAULT_BASE_URL: &str = "https://xivapi.com";
+
+/// Builder used to configure an `XIVAPIClient`.
+///
+/// Created with `XIVAPIClient::builder()`.
+///
+/// # Examples
+/// ```no_run
+/// use std::time::Duration;
+/// use xivapi_rust::XIVAPIClient;
+///
+/// # fn main() -> Result<(), xivapi_rust::Error> {
+/// let client = XIVAPIClient::builder()
+///     .base_url("http://localhost:8080")
+///     .timeout(Duration::from_secs(10))
+///     .user_agent("my-discord-bot/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct XIVAPIClientBuilder {
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    client: Option<Client>,
+    private_keys: Vec<PrivateKey>,
+    rate_limit: Option<RateLimit>,
+    retry: Option<RetryPolicy>,
+    cache: Option<CacheConfig>,
+    disk_cache: Option<PathBuf>,
+    stale_if_error: bool,
+    offline: bool,
+    language: Option<Language>,
+    validate_servers: Option<bool>,
+}
+
+impl XIVAPIClientBuilder {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base URL all requests are sent to. Defaults to `https://xivapi.com`.
+    ///
+    /// Useful for self-hosted mirrors or local mock servers.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the total timeout for each request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with each request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Routes all requests through the given proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Uses an existing `reqwest::Client` instead of creating a new one.
+    ///
+    /// The timeout, user agent and proxy options can't be combined with this, since they
+    /// have to be set on the `reqwest::Client` itself.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Attaches an XIVAPI private key to every request, which grants higher rate limits.
+    ///
+    /// Can be called multiple times to build a pool of keys. When a key gets rate limited,
+    /// the client rotates to the next one.
+    pub fn private_key(mut self, key: impl Into<String>) -> Self {
+        self.private_keys.push(PrivateKey::new(key));
+        self
+    }
+
+    /// Adds several private keys to the key pool at once.
+    pub fn private_keys<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.private_keys
+            .extend(keys.into_iter().map(PrivateKey::new));
+        self
+    }
+
+    /// Adds the private key stored in the `XIVAPI_KEY` environment variable, if it is set.
+    pub fn private_key_from_env(self) -> Self {
+        match std::env::var(PRIVATE_KEY_ENV) {
+            Ok(key) if !key.trim().is_empty() => self.private_key(key.trim()),
+            _ => self,
+        }
+    }
+
+    /// Enables the client-side rate limiter.
+    ///
+    /// Requests over the limit wait for their turn instead of being rejected by XIVAPI.
+    /// Each private key gets its own bucket.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Retries failed requests according to the given policy.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Enables the in-memory response cache.
+    pub fn cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Stores responses in the given directory, so they survive restarts of the program.
+    ///
+    /// Uses the TTLs from `cache()`, or the defaults from `CacheConfig::new()` if the in-memory cache
+    /// isn't enabled.
+    pub fn disk_cache(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.disk_cache = Some(directory.into());
+        self
+    }
+
+    /// Serves the last cached response, marked as stale, when XIVAPI can't be reached or fails
+    /// with a server error or rate limit.
+    pub fn stale_if_error(mut self, enabled: bool) -> Self {
+        self.stale_if_error = enabled;
+        self
+    }
+
+    /// Never sends requests to XIVAPI and only serves cached responses.
+    ///
+    /// Requests without a cached response fail with `Error::Offline`.
+    pub fn offline(mut self, enabled: bool) -> Self {
+        self.offline = enabled;
+        self
+    }
+
+    /// Sets the language responses are localized in. Defaults to XIVAPI's own default, English.
+    ///
+    /// Can be overridden for a single request with the `language()` option of the request builders.
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets whether the `server` filter of searches is checked against the list of worlds and
+    /// data centers before the search is sent. Enabled by default.
+    ///
+    /// The list is requested once, the first time a search is filtered by server. Searches for
+    /// unknown servers fail with `Error::UnknownServer` instead of returning no results. If the
+    /// list can't be requested, searches filtered by server fail until it can, so disable it to
+    /// send them unchecked instead.
+    pub fn validate_servers(mut self, enabled: bool) -> Self {
+        self.validate_servers = Some(enabled);
+        self
+    }
+
+    /// Builds the `XIVAPIClient`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the configured `XIVAPIClient` or an `Error` if the base URL is invalid,
+    /// the options conflict, or the HTTP client could not be created.
+    pub fn build(self) -> Result<XIVAPIClient, Error> {
+        let base_url = self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        let base_url = Url::parse(base_url).map_err(|error| {
+            Error::Config(format!("invalid base URL `{}`: {}", base_url, error))
+        })?;
+
+        if base_url.cannot_be_a_base() {
+            return Err(Error::Config(format!(
+                "`{}` can't be used as a base URL",
+                base_url
+            )));
+        }
+
+        let client = match self.client {
+            Some(client) => {
+                if self.timeout.is_some()
+                    || self.connect_timeout.is_some()
+                    || self.user_agent.is_some()
+                    || self.proxy.is_some()
+                {
+                    return Err(Error::Config(
+                        "timeouts, user agent and proxy can't be set when providing a reqwest::Client"
+                            .to_string(),
+                    ));
+                }
+
+                client
+            }
+            None => {
+                let mut builder = Client::builder();
+
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+
+                builder.build()?
+            }
+        };
+
+        let disk = match self.disk_cache {
+            Some(directory) => {
+                let config = self.cache.clone().unwrap_or_default();
+                let disk = DiskCache::open(&directory, config).map_err(|error| {
+                    Error::Config(format!(
+                        "can't open disk cache at `{}`: {}",
+                        directory.display(),
+                        error
+                    ))
+                })?;
+
+                Some(Arc::new(disk))
+            }
+            None => None,
+        };
+
+        let keys = if self.private_keys.is_empty() {
+            None
+        } else {
+            Some(Arc::new(KeyPool::new(self.private_keys)))
+        };
+
+        Ok(XIVAPIClient {
+            client,
+            base_url,
+            keys,
+            limiter: self
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+            retry: self.retry,
+            cache: self.cache.map(|config| Arc::new(MemoryCache::new(config))),
+            disk,
+            stale_if_error: self.stale_if_error,
+            offline: self.offline,
+            language: self.language,
+            servers: self.validate_servers.unwrap_or(true).then(Arc::default),
+            patches: Arc::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+
+    use crate::{Error, XIVAPIClient};
+
+    #[test]
+    fn test_invalid_base_url() {
+        let result = XIVAPIClient::builder().base_url("not a url").build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_client_conflicts_with_user_agent() {
+        let result = XIVAPIClient::builder()
+            .client(Client::new())
+            .user_agent("test")
+            .build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+}
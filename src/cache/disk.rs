@@ -0,0 +1,259 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use super::{CacheConfig, CachedResponse, Endpoint};
+
+/// The file stored on disk for every cached response.
+#[derive(Deserialize, Serialize)]
+struct Envelope {
+    key: String,
+    url: String,
+    stored_at: u64,
+    body: String,
+}
+
+/// Directory-backed store of raw XIVAPI responses, shared between runs of a program.
+///
+/// Every response is written to its own JSON file, named after a hash of its cache key.
+#[derive(Debug)]
+pub(crate) struct DiskCache {
+    directory: PathBuf,
+    config: CacheConfig,
+}
+
+impl DiskCache {
+    /// Opens the cache in `directory`, creating it if it doesn't exist.
+    pub(crate) fn open(directory: impl Into<PathBuf>, config: CacheConfig) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+
+        Ok(DiskCache { directory, config })
+    }
+
+    /// Reads the response stored for a key, if it hasn't expired.
+    pub(crate) async fn get(&self, endpoint: Endpoint, key: &str) -> Option<CachedResponse> {
+        self.get_stale(key)
+            .await
+            .filter(|response| !response.is_expired(self.config.ttl(endpoint)))
+    }
+
+    /// Reads the response stored for a key, regardless of its age.
+    ///
+    /// Unreadable or corrupted files are treated as missing.
+    pub(crate) async fn get_stale(&self, key: &str) -> Option<CachedResponse> {
+        let contents = tokio::fs::read(self.path(key)).await.ok()?;
+        let envelope: Envelope = serde_json::from_slice(&contents).ok()?;
+
+        // Guard against hash collisions.
+        if envelope.key != key {
+            return None;
+        }
+
+        Some(CachedResponse {
+            url: Url::parse(&envelope.url).ok()?,
+            body: Bytes::from(envelope.body),
+            stored_at: UNIX_EPOCH + Duration::from_secs(envelope.stored_at),
+        })
+    }
+
+    /// Writes a response to disk.
+    pub(crate) async fn insert(&self, key: &str, response: &CachedResponse) -> io::Result<()> {
+        let envelope = Envelope {
+            key: key.to_string(),
+            url: response.url.to_string(),
+            stored_at: response
+                .stored_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            body: String::from_utf8_lossy(&response.body).into_owned(),
+        };
+
+        let contents = serde_json::to_vec(&envelope)?;
+
+        // Write to a temporary file first so readers never see a partially written response.
+        // Each write gets its own file, so concurrent writes of the same key can't interleave.
+        let path = self.path(key);
+        let temporary = path.with_extension(format!("{:016x}.tmp", rand::random::<u64>()));
+
+        let written = match tokio::fs::write(&temporary, contents).await {
+            Ok(()) => tokio::fs::rename(&temporary, &path).await,
+            Err(error) => Err(error),
+        };
+
+        if written.is_err() {
+            let _ = tokio::fs::remove_file(&temporary).await;
+        }
+
+        written
+    }
+
+    /// Removes every stored response for the given URL path, regardless of query parameters.
+    pub(crate) async fn invalidate_path(&self, path: &str) -> io::Result<()> {
+        let path = path.to_string();
+
+        self.remove_where(move |envelope| {
+            Url::parse(&envelope.url).is_ok_and(|url| url.path() == path)
+        })
+        .await
+    }
+
+    /// Removes every stored response.
+    pub(crate) async fn clear(&self) -> io::Result<()> {
+        self.remove_where(|_| true).await
+    }
+
+    /// Removes the stored responses that match `predicate`, scanning the directory on the
+    /// blocking thread pool.
+    ///
+    /// Files that weren't written by the cache, or can't be read, are left alone.
+    async fn remove_where(
+        &self,
+        predicate: impl Fn(&Envelope) -> bool + Send + 'static,
+    ) -> io::Result<()> {
+        let directory = self.directory.clone();
+
+        tokio::task::spawn_blocking(move || {
+            for entry in fs::read_dir(&directory)? {
+                let path = entry?.path();
+
+                if !is_cache_file(&path) {
+                    continue;
+                }
+
+                let matches = fs::read(&path)
+                    .ok()
+                    .and_then(|contents| serde_json::from_slice::<Envelope>(&contents).ok())
+                    .is_some_and(|envelope| predicate(&envelope));
+
+                if matches {
+                    fs::remove_file(&path)?;
+                }
+            }
+
+            Ok(())
+        })
+        .await
+        .map_err(io::Error::other)?
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.json", fnv1a(key)))
+    }
+}
+
+/// Whether a file is named like the ones `DiskCache::path()` writes, e.g. `00c0ffee00c0ffee.json`.
+fn is_cache_file(path: &Path) -> bool {
+    let is_hash =
+        |stem: &str| stem.len() == 16 && stem.bytes().all(|byte| byte.is_ascii_hexdigit());
+
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+        && path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(is_hash)
+}
+
+/// 64-bit FNV-1a, used because file names have to stay stable between builds.
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use bytes::Bytes;
+    use reqwest::Url;
+
+    use super::DiskCache;
+    use crate::cache::{CacheConfig, CachedResponse, Endpoint};
+
+    fn directory(name: &str) -> std::path::PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("xivapi-rust-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_and_invalidate() {
+        let directory = directory("disk-cache");
+        let cache = DiskCache::open(&directory, CacheConfig::new()).unwrap();
+        let url = Url::parse("https://xivapi.com/character/1?data=AC").unwrap();
+        let key = url.to_string();
+        let stored_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        cache
+            .insert(
+                &key,
+                &CachedResponse {
+                    url,
+                    body: Bytes::from_static(br#"{"Character":{}}"#),
+                    stored_at,
+                },
+            )
+            .await
+            .unwrap();
+
+        let reopened = DiskCache::open(&directory, CacheConfig::new()).unwrap();
+        let cached = reopened.get_stale(&key).await.unwrap();
+        assert_eq!(cached.body.as_ref(), br#"{"Character":{}}"#);
+        assert_eq!(cached.stored_at, stored_at);
+        assert!(reopened.get(Endpoint::Character, &key).await.is_none());
+
+        let unrelated = directory.join("settings.json");
+        let corrupted = directory.join("0123456789abcdef.json");
+        std::fs::write(&unrelated, "{}").unwrap();
+        std::fs::write(&corrupted, "not json").unwrap();
+
+        cache.invalidate_path("/character/1").await.unwrap();
+        assert!(cache.get_stale(&key).await.is_none());
+        assert!(unrelated.exists());
+        assert!(corrupted.exists());
+
+        cache.clear().await.unwrap();
+        assert!(unrelated.exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_inserts() {
+        let directory = directory("disk-cache-concurrent");
+        let cache = DiskCache::open(&directory, CacheConfig::new()).unwrap();
+        let url = Url::parse("https://xivapi.com/character/1").unwrap();
+        let key = url.to_string();
+
+        let inserts = (0..16).map(|index| {
+            let response = CachedResponse {
+                url: url.clone(),
+                body: Bytes::from(format!(r#"{{"Index":{index}}}"#)),
+                stored_at: SystemTime::now(),
+            };
+            let cache = &cache;
+            let key = &key;
+
+            async move { cache.insert(key, &response).await }
+        });
+
+        for written in futures::future::join_all(inserts).await {
+            written.unwrap();
+        }
+
+        assert!(cache.get_stale(&key).await.is_some());
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
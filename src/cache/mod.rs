@@ -0,0 +1,418 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use reqwest::Url;
+
+use crate::keys::PRIVATE_KEY_PARAM;
+
+pub(crate) use disk::DiskCache;
+
+mod disk;
+
+/// The kind of data an endpoint returns, used to pick a cache TTL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Endpoint {
+    /// Name-based searches.
+    Search,
+    /// Character lookups by ID.
+    Character,
+    /// Free Company lookups by ID.
+    FreeCompany,
+    /// Linkshell and cross-world linkshell lookups by ID.
+    Linkshell,
+    /// PvP team lookups by ID.
+    PvPTeam,
+    /// Lodestone news feeds.
+    Lodestone,
+    /// Static game data, such as sheets and patch information.
+    GameData,
+}
+
+/// Settings for the in-memory response cache.
+///
+/// Responses are keyed by their normalized request URL, and every kind of endpoint has its own
+/// time-to-live. Once the cache is full, the least recently used response is evicted.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use xivapi_rust::{CacheConfig, XIVAPIClient};
+///
+/// # fn main() -> Result<(), xivapi_rust::Error> {
+/// let client = XIVAPIClient::builder()
+///     .cache(
+///         CacheConfig::new()
+///             .capacity(500)
+///             .character_ttl(Duration::from_secs(60 * 60)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CacheConfig {
+    capacity: usize,
+    search_ttl: Duration,
+    character_ttl: Duration,
+    free_company_ttl: Duration,
+    linkshell_ttl: Duration,
+    pvp_team_ttl: Duration,
+    lodestone_ttl: Duration,
+    game_data_ttl: Duration,
+}
+
+impl CacheConfig {
+    /// Creates the default configuration: 1000 responses, Lodestone news cached for 5 minutes,
+    /// searches for 10 minutes, character, Free Company, linkshell and PvP team lookups for 2 hours
+    /// and game data for a day.
+    pub fn new() -> Self {
+        CacheConfig {
+            capacity: 1000,
+            search_ttl: Duration::from_secs(10 * 60),
+            character_ttl: Duration::from_secs(2 * 60 * 60),
+            free_company_ttl: Duration::from_secs(2 * 60 * 60),
+            linkshell_ttl: Duration::from_secs(2 * 60 * 60),
+            pvp_team_ttl: Duration::from_secs(2 * 60 * 60),
+            lodestone_ttl: Duration::from_secs(5 * 60),
+            game_data_ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+
+    /// Sets the maximum number of responses kept in memory.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets how long search results are cached.
+    pub fn search_ttl(mut self, ttl: Duration) -> Self {
+        self.search_ttl = ttl;
+        self
+    }
+
+    /// Sets how long character lookups are cached.
+    pub fn character_ttl(mut self, ttl: Duration) -> Self {
+        self.character_ttl = ttl;
+        self
+    }
+
+    /// Sets how long Free Company lookups are cached.
+    pub fn free_company_ttl(mut self, ttl: Duration) -> Self {
+        self.free_company_ttl = ttl;
+        self
+    }
+
+    /// Sets how long linkshell and cross-world linkshell lookups are cached.
+    pub fn linkshell_ttl(mut self, ttl: Duration) -> Self {
+        self.linkshell_ttl = ttl;
+        self
+    }
+
+    /// Sets how long PvP team lookups are cached.
+    pub fn pvp_team_ttl(mut self, ttl: Duration) -> Self {
+        self.pvp_team_ttl = ttl;
+        self
+    }
+
+    /// Sets how long Lodestone news feeds are cached.
+    pub fn lodestone_ttl(mut self, ttl: Duration) -> Self {
+        self.lodestone_ttl = ttl;
+        self
+    }
+
+    /// Sets how long static game data is cached.
+    pub fn game_data_ttl(mut self, ttl: Duration) -> Self {
+        self.game_data_ttl = ttl;
+        self
+    }
+
+    pub(crate) fn ttl(&self, endpoint: Endpoint) -> Duration {
+        match endpoint {
+            Endpoint::Search => self.search_ttl,
+            Endpoint::Character => self.character_ttl,
+            Endpoint::FreeCompany => self.free_company_ttl,
+            Endpoint::Linkshell => self.linkshell_ttl,
+            Endpoint::PvPTeam => self.pvp_team_ttl,
+            Endpoint::Lodestone => self.lodestone_ttl,
+            Endpoint::GameData => self.game_data_ttl,
+        }
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where a response returned by the client came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Freshness {
+    /// Fetched from XIVAPI for this request.
+    Live,
+    /// Served from the cache, within the TTL for its endpoint.
+    Cached {
+        /// When the response was fetched from XIVAPI.
+        stored_at: SystemTime,
+    },
+    /// Served from the cache past its TTL, because XIVAPI couldn't be reached or the client is offline.
+    Stale {
+        /// When the response was fetched from XIVAPI.
+        stored_at: SystemTime,
+    },
+}
+
+/// A response along with information about whether it came from the cache.
+#[derive(Debug)]
+pub struct Cached<T> {
+    /// The response data.
+    pub data: T,
+    /// Where the response came from.
+    pub freshness: Freshness,
+}
+
+impl<T> Cached<T> {
+    /// Returns `true` if the data is out of date and was only served because fresh data was unavailable.
+    pub fn is_stale(&self) -> bool {
+        matches!(self.freshness, Freshness::Stale { .. })
+    }
+
+    /// Returns the response data, discarding where it came from.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+/// A response stored in the cache.
+#[derive(Clone, Debug)]
+pub(crate) struct CachedResponse {
+    pub(crate) url: Url,
+    pub(crate) body: Bytes,
+    pub(crate) stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Whether the response is older than the TTL for its endpoint.
+    pub(crate) fn is_expired(&self, ttl: Duration) -> bool {
+        SystemTime::now()
+            .duration_since(self.stored_at)
+            .unwrap_or_default()
+            > ttl
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    response: CachedResponse,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    by_key: HashMap<String, Entry>,
+    by_use: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Entries {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.by_key.remove(key) {
+            self.by_use.remove(&entry.last_used);
+        }
+    }
+}
+
+/// In-memory LRU cache of raw XIVAPI responses.
+#[derive(Debug)]
+pub(crate) struct MemoryCache {
+    config: CacheConfig,
+    entries: Mutex<Entries>,
+}
+
+impl MemoryCache {
+    pub(crate) fn new(config: CacheConfig) -> Self {
+        MemoryCache {
+            config,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns the cached response for a key, if it exists and hasn't expired.
+    pub(crate) fn get(&self, endpoint: Endpoint, key: &str) -> Option<CachedResponse> {
+        self.lookup(key, Some(self.config.ttl(endpoint)))
+    }
+
+    /// Returns the cached response for a key even if it has expired.
+    pub(crate) fn get_stale(&self, key: &str) -> Option<CachedResponse> {
+        self.lookup(key, None)
+    }
+
+    fn lookup(&self, key: &str, ttl: Option<Duration>) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.tick += 1;
+        let tick = entries.tick;
+
+        let entry = entries.by_key.get_mut(key)?;
+
+        // Expired responses are kept around until they're evicted, so they can still be
+        // served as stale when XIVAPI is unreachable.
+        if ttl.is_some_and(|ttl| entry.response.is_expired(ttl)) {
+            return None;
+        }
+
+        let previous = std::mem::replace(&mut entry.last_used, tick);
+        let response = entry.response.clone();
+
+        entries.by_use.remove(&previous);
+        entries.by_use.insert(tick, key.to_string());
+
+        Some(response)
+    }
+
+    /// Stores a response, evicting the least recently used one if the cache is full.
+    pub(crate) fn insert(&self, key: String, response: CachedResponse) {
+        if self.config.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        entries.remove(&key);
+
+        while entries.by_key.len() >= self.config.capacity {
+            let Some((_, oldest)) = entries.by_use.pop_first() else {
+                break;
+            };
+            entries.by_key.remove(&oldest);
+        }
+
+        entries.tick += 1;
+        let tick = entries.tick;
+
+        entries.by_use.insert(tick, key.clone());
+        entries.by_key.insert(
+            key,
+            Entry {
+                response,
+                last_used: tick,
+            },
+        );
+    }
+
+    /// Removes every cached response for the given URL path, regardless of query parameters.
+    pub(crate) fn invalidate_path(&self, path: &str) {
+        let mut entries = self.entries.lock().unwrap();
+
+        let keys: Vec<String> = entries
+            .by_key
+            .iter()
+            .filter(|(_, entry)| entry.response.url.path() == path)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in keys {
+            entries.remove(&key);
+        }
+    }
+
+    /// Removes every cached response.
+    pub(crate) fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.by_key.clear();
+        entries.by_use.clear();
+    }
+}
+
+/// Turns a request URL into a cache key.
+///
+/// Query parameters are sorted, empty queries are dropped and the private key is removed, so
+/// equivalent requests share an entry.
+pub(crate) fn normalize(url: &Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != PRIVATE_KEY_PARAM)
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    pairs.sort();
+
+    let mut url = url.clone();
+    url.set_fragment(None);
+
+    if pairs.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use bytes::Bytes;
+    use reqwest::Url;
+
+    use super::{normalize, CacheConfig, CachedResponse, Endpoint, MemoryCache};
+
+    fn response(url: &str) -> (String, CachedResponse) {
+        let url = Url::parse(url).unwrap();
+        let key = normalize(&url);
+
+        (
+            key,
+            CachedResponse {
+                url,
+                body: Bytes::from_static(b"{}"),
+                stored_at: SystemTime::now(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = Url::parse("https://xivapi.com/character/1?extended=1&data=AC").unwrap();
+        let b =
+            Url::parse("https://xivapi.com/character/1?data=AC&extended=1&private_key=x").unwrap();
+        let c = Url::parse("https://xivapi.com/character/1?").unwrap();
+
+        assert_eq!(normalize(&a), normalize(&b));
+        assert_eq!(normalize(&c), "https://xivapi.com/character/1");
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = MemoryCache::new(CacheConfig::new().capacity(2));
+        let (first, first_response) = response("https://xivapi.com/character/1");
+        let (second, second_response) = response("https://xivapi.com/character/2");
+        let (third, third_response) = response("https://xivapi.com/character/3");
+
+        cache.insert(first.clone(), first_response);
+        cache.insert(second.clone(), second_response);
+        assert!(cache.get(Endpoint::Character, &first).is_some());
+
+        cache.insert(third.clone(), third_response);
+
+        assert!(cache.get(Endpoint::Character, &first).is_some());
+        assert!(cache.get(Endpoint::Character, &second).is_none());
+        assert!(cache.get(Endpoint::Character, &third).is_some());
+    }
+
+    #[test]
+    fn test_ttl_and_invalidation() {
+        let cache = MemoryCache::new(CacheConfig::new().search_ttl(Duration::ZERO));
+        let (key, cached) = response("https://xivapi.com/character/1?data=AC");
+
+        cache.insert(key.clone(), cached.clone());
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(cache.get(Endpoint::Search, &key).is_none());
+
+        cache.insert(key.clone(), cached);
+        cache.invalidate_path("/character/1");
+        assert!(cache.get(Endpoint::Character, &key).is_none());
+    }
+}
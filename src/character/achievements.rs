@@ -1,18 +1,233 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize, Debug)]
-#[serde(rename_all = "PascalCase")]
+use super::extended::GameObject;
+use crate::{request::SheetRowRequest, Error, LocalizedNames, XIVAPIClient};
+
+/// The columns requested for achievements.
+const COLUMNS: [&str; 17] = [
+    "ID",
+    "Name",
+    "Name_en",
+    "Name_ja",
+    "Name_de",
+    "Name_fr",
+    "Description",
+    "Points",
+    "Icon",
+    "AchievementCategory.ID",
+    "AchievementCategory.Name",
+    "Title.ID",
+    "Title.Masculine",
+    "Title.Feminine",
+    "Item.ID",
+    "Item.Name",
+    "Item.Icon",
+];
+
 /// Unlocked achievements.
 ///
 /// Only returned if achievements are public.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
 pub struct CharacterAchievements {
-    list: Vec<Achievements>,
-    points: u32,
+    /// The achievements the character has unlocked.
+    pub list: Vec<UnlockedAchievement>,
+    /// The character's total achievement points.
+    pub points: u32,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl CharacterAchievements {
+    /// Returns the most recently unlocked achievements, newest first.
+    pub fn recent(&self, count: usize) -> Vec<&UnlockedAchievement> {
+        let mut unlocked: Vec<&UnlockedAchievement> = self.list.iter().collect();
+        unlocked.sort_by_key(|unlocked| Reverse(unlocked.unlocked_at));
+        unlocked.truncate(count);
+        unlocked
+    }
+}
+
+/// An achievement a character has unlocked.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnlockedAchievement {
+    /// The ID of the achievement on the `Achievement` sheet.
+    #[serde(rename = "ID")]
+    pub achievement_id: u32,
+    /// When the character unlocked the achievement.
+    #[serde(rename = "Date", with = "chrono::serde::ts_seconds")]
+    pub unlocked_at: DateTime<Utc>,
+}
+
+/// An achievement from the `Achievement` sheet.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Achievement {
+    /// The unique ID of the achievement.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The name of the achievement.
+    pub name: String,
+    /// The name of the achievement in each language, if XIVAPI included them.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+    /// What has to be done to unlock the achievement.
+    #[serde(default)]
+    pub description: String,
+    /// The number of achievement points the achievement is worth.
+    #[serde(default)]
+    pub points: u32,
+    /// The path of the achievement's icon.
+    #[serde(default)]
+    pub icon: String,
+    /// The category the achievement is listed under, if it has one.
+    #[serde(rename = "AchievementCategory", default)]
+    pub category: Option<GameObject>,
+    /// The title rewarded for the achievement, if there is one.
+    #[serde(rename = "Title", default)]
+    pub reward_title: Option<RewardTitle>,
+    /// The item rewarded for the achievement, if there is one.
+    #[serde(rename = "Item", default)]
+    pub reward_item: Option<GameObject>,
+}
+
+impl Achievement {
+    /// XIVAPI expands unset links into rows with an ID of 0.
+    fn without_empty_rewards(mut self) -> Self {
+        self.category = self.category.filter(|category| category.id != 0);
+        self.reward_title = self.reward_title.filter(|title| title.id != 0);
+        self.reward_item = self.reward_item.filter(|item| item.id != 0);
+        self
+    }
+}
+
+/// A title rewarded for an achievement.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
-struct Achievements {
-    list: Vec<String>,
-    points: u32,
+pub struct RewardTitle {
+    /// The unique ID of the title.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The masculine form of the title.
+    #[serde(default)]
+    pub masculine: String,
+    /// The feminine form of the title.
+    #[serde(default)]
+    pub feminine: String,
+}
+
+impl XIVAPIClient {
+    /// Gets an achievement from the `Achievement` sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the achievement, e.g. `UnlockedAchievement::achievement_id`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Achievement` or an `Error` if the request to XIVAPI fails.
+    pub async fn achievement(&self, id: u32) -> Result<Achievement, Error> {
+        let achievement: Achievement = SheetRowRequest::new("Achievement", id)
+            .columns(COLUMNS)
+            .send(self)
+            .await?;
+
+        Ok(achievement.without_empty_rewards())
+    }
+
+    /// Gets several achievements from the `Achievement` sheet, in as few requests as possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the achievements.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the achievements as `Vec<Achievement>`, leaving out IDs that don't
+    /// exist, or an `Error` if a request to XIVAPI fails.
+    pub async fn achievements(&self, ids: &[u32]) -> Result<Vec<Achievement>, Error> {
+        let achievements: Vec<Achievement> =
+            self.sheet_rows_by_ids("Achievement", ids, &COLUMNS).await?;
+
+        Ok(achievements
+            .into_iter()
+            .map(Achievement::without_empty_rewards)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer,
+    };
+
+    use super::CharacterAchievements;
+    use crate::{sheet::tests::sheet_page, Error, XIVAPIClient};
+
+    #[tokio::test]
+    async fn test_recent_achievements() -> Result<(), Error> {
+        let unlocked: CharacterAchievements = serde_json::from_str(
+            r#"{
+                "List": [
+                    {"Date": 1592339546, "ID": 2450},
+                    {"Date": 1697500800, "ID": 1},
+                    {"Date": 1650000000, "ID": 308}
+                ],
+                "Points": 25
+            }"#,
+        )
+        .unwrap();
+
+        let recent = unlocked.recent(2);
+        assert_eq!(recent[0].achievement_id, 1);
+        assert_eq!(recent[1].achievement_id, 308);
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/Achievement"))
+            .and(query_param("ids", "1,308"))
+            .respond_with(sheet_page(
+                1,
+                1,
+                r#"[{
+                        "ID": 1, "Name": "To Crush Your Enemies I",
+                        "Description": "Defeat 100 enemies.", "Points": 5,
+                        "Icon": "/i/006000/006001.png",
+                        "AchievementCategory": {"ID": 1, "Name": "Battle"},
+                        "Title": {"ID": 0, "Masculine": "", "Feminine": ""},
+                        "Item": null
+                    }, {
+                        "ID": 308, "Name": "Mastering War I",
+                        "Description": "Complete all Gladiator class quests.", "Points": 10,
+                        "Icon": "/i/006000/006101.png",
+                        "AchievementCategory": {"ID": 2, "Name": "Classes"},
+                        "Title": {"ID": 12, "Masculine": "Gladiator", "Feminine": "Gladiatrix"},
+                        "Item": {"ID": 6003, "Name": "Company Chocobo Barding", "Icon": null}
+                    }]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let ids: Vec<u32> = recent
+            .iter()
+            .map(|unlocked| unlocked.achievement_id)
+            .collect();
+        let achievements = client.achievements(&ids).await?;
+
+        assert_eq!(achievements[0].reward_title, None);
+        assert_eq!(achievements[0].category.as_ref().unwrap().name, "Battle");
+        assert_eq!(
+            achievements[1].reward_title.as_ref().unwrap().feminine,
+            "Gladiatrix"
+        );
+        assert_eq!(achievements[1].reward_item.as_ref().unwrap().id, 6003);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-
 /// Class information.
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "PascalCase")]
@@ -64,4 +63,4 @@ pub struct ClassElemental {
     pub level: u32,
     /// The name of the elemental class.
     pub name: String,
-}
\ No newline at end of file
+}
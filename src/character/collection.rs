@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterData, CharacterResult, Mimo};
+use crate::{
+    request::SheetRowsRequest, sheet::SheetPage, Error, Language, LocalizedNames, XIVAPIClient,
+};
+
+/// The most rows XIVAPI returns on a single page of a sheet.
+const PAGE_LIMIT: u32 = 3000;
+
+/// The columns requested for mounts and minions.
+const COLUMNS: [&str; 8] = [
+    "ID",
+    "Name",
+    "Name_en",
+    "Name_ja",
+    "Name_de",
+    "Name_fr",
+    "Icon",
+    "GameContentLinks",
+];
+
+/// A kind of collectible a character can own.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollectionKind {
+    /// Mounts, from the `Mount` sheet.
+    Mounts,
+    /// Minions, from the `Companion` sheet.
+    Minions,
+}
+
+impl CollectionKind {
+    /// The sheet the collectibles are listed in.
+    pub fn sheet(self) -> &'static str {
+        match self {
+            CollectionKind::Mounts => "Mount",
+            CollectionKind::Minions => "Companion",
+        }
+    }
+
+    fn owned<C>(self, result: &CharacterResult<C>) -> Option<&[Mimo]> {
+        match self {
+            CollectionKind::Mounts => result.mounts.as_deref(),
+            CollectionKind::Minions => result.minions.as_deref(),
+        }
+    }
+}
+
+/// A mount or minion from the game's sheets.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Collectible {
+    /// The ID of the row on the `Mount` or `Companion` sheet.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The name of the mount or minion.
+    #[serde(default)]
+    pub name: String,
+    /// The name in each language, for any of the `Name_<lang>` fields XIVAPI included.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+    /// The path of the icon, e.g. `/i/004000/004001.png`.
+    #[serde(default)]
+    pub icon: String,
+    /// The sheets that refer to the mount or minion, such as `Achievement`, `Quest` or
+    /// `ItemAction`, which hint at how it is obtained.
+    #[serde(rename = "GameContentLinks", default, deserialize_with = "sources")]
+    pub sources: Vec<String>,
+}
+
+impl Collectible {
+    /// Returns true if the character's mount or minion is this collectible.
+    ///
+    /// Icons are compared first. Names are compared in `language`, which should be the language
+    /// the character was looked up in, and in every language both sides include.
+    pub fn matches(&self, mimo: &Mimo, language: Language) -> bool {
+        if !self.icon.is_empty() && mimo.icon.ends_with(&self.icon) {
+            return true;
+        }
+
+        let name = self.names.get(language).unwrap_or(&self.name);
+
+        if !name.is_empty() && name.eq_ignore_ascii_case(&mimo.name) {
+            return true;
+        }
+
+        Language::ALL.into_iter().any(|language| {
+            match (self.names.get(language), mimo.names.get(language)) {
+                (Some(ours), Some(theirs)) => !ours.is_empty() && ours.eq_ignore_ascii_case(theirs),
+                _ => false,
+            }
+        })
+    }
+}
+
+/// Reads the sheet names out of `GameContentLinks`, which XIVAPI sends as `[]` when empty.
+fn sources<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let links = Option::<serde_json::Value>::deserialize(deserializer)?;
+
+    let mut sources: Vec<String> = links
+        .as_ref()
+        .and_then(serde_json::Value::as_object)
+        .map(|links| links.keys().cloned().collect())
+        .unwrap_or_default();
+    sources.sort();
+
+    Ok(sources)
+}
+
+/// How much of a collection a character owns.
+#[derive(Serialize, Clone, Debug)]
+pub struct CollectionReport {
+    /// The kind of collectibles in the report.
+    pub kind: CollectionKind,
+    /// The collectibles the character owns.
+    pub owned: Vec<Collectible>,
+    /// The collectibles the character doesn't own.
+    pub missing: Vec<Collectible>,
+    /// The names of the character's mounts or minions that matched no collectible.
+    pub unmatched: Vec<String>,
+}
+
+impl CollectionReport {
+    /// Matches a character's mounts or minions against every collectible of a kind.
+    ///
+    /// Rows without a name, which are placeholders in the sheets, are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of collectibles, e.g. `CollectionKind::Mounts`.
+    /// * `owned` - The character's mounts or minions.
+    /// * `collectibles` - Every collectible of the kind, e.g. from `XIVAPIClient::collectibles()`.
+    /// * `language` - The language the character was looked up in.
+    pub fn new(
+        kind: CollectionKind,
+        owned: &[Mimo],
+        collectibles: Vec<Collectible>,
+        language: Language,
+    ) -> Self {
+        let mut matched = vec![false; owned.len()];
+        let mut report = CollectionReport {
+            kind,
+            owned: Vec::new(),
+            missing: Vec::new(),
+            unmatched: Vec::new(),
+        };
+
+        for collectible in collectibles {
+            if collectible.name.is_empty() && collectible.names.is_empty() {
+                continue;
+            }
+
+            let position = owned
+                .iter()
+                .zip(&matched)
+                .position(|(mimo, &matched)| !matched && collectible.matches(mimo, language));
+
+            match position {
+                Some(position) => {
+                    matched[position] = true;
+                    report.owned.push(collectible);
+                }
+                None => report.missing.push(collectible),
+            }
+        }
+
+        report.unmatched = owned
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(mimo, _)| mimo.name.clone())
+            .collect();
+
+        report
+    }
+
+    /// Returns the number of collectibles there are to own.
+    pub fn total(&self) -> usize {
+        self.owned.len() + self.missing.len()
+    }
+
+    /// Returns the share of the collection the character owns, from 0 to 100.
+    pub fn percentage(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.owned.len() as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+impl XIVAPIClient {
+    /// Lists every mount or minion in the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of collectibles to list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the collectibles as `Vec<Collectible>` or an `Error`
+    /// if a request to XIVAPI fails.
+    pub async fn collectibles(&self, kind: CollectionKind) -> Result<Vec<Collectible>, Error> {
+        let mut collectibles = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let rows: SheetPage<Collectible> = SheetRowsRequest::new(kind.sheet())
+                .page(page)
+                .limit(PAGE_LIMIT)
+                .columns(COLUMNS)
+                .send(self)
+                .await?;
+
+            collectibles.extend(rows.results);
+
+            match rows.pagination.page_next {
+                Some(next) if next > page => page = next,
+                _ => return Ok(collectibles),
+            }
+        }
+    }
+
+    /// Reports how much of the mount or minion collection a character owns.
+    ///
+    /// Names are matched in the client's language, or English if it has none. Use
+    /// `CollectionReport::new()` if the character was looked up in another language.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - A character lookup made with `CharacterData::MOUNTS_MINIONS`.
+    /// * `kind` - The collection to report on.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `CollectionReport`, `Error::MissingSections` if the lookup
+    /// didn't include mounts and minions, or another `Error` if a request to XIVAPI fails.
+    pub async fn collection_report<C>(
+        &self,
+        result: &CharacterResult<C>,
+        kind: CollectionKind,
+    ) -> Result<CollectionReport, Error> {
+        let owned = kind
+            .owned(result)
+            .ok_or(Error::MissingSections(CharacterData::MOUNTS_MINIONS))?;
+        let collectibles = self.collectibles(kind).await?;
+
+        Ok(CollectionReport::new(
+            kind,
+            owned,
+            collectibles,
+            self.language.unwrap_or_default(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer,
+    };
+
+    use super::CollectionKind;
+    use crate::{character::CharacterResult, sheet::tests::sheet_page, Error, XIVAPIClient};
+
+    #[tokio::test]
+    async fn test_collection_report() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/Mount"))
+            .and(query_param("page", "1"))
+            .respond_with(sheet_page(
+                1,
+                2,
+                r#"[
+                    {"ID": 0, "Name": "", "Icon": "", "GameContentLinks": []},
+                    {"ID": 1, "Name": "Company Chocobo", "Icon": "/i/004000/004001.png",
+                     "GameContentLinks": {"Quest": {"Mount": [66236]}}}
+                ]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/Mount"))
+            .and(query_param("page", "2"))
+            .respond_with(sheet_page(
+                2,
+                2,
+                r#"[
+                    {"ID": 4, "Name": "Unicorn", "Name_de": "Einhorn", "Icon": "/i/004000/004004.png",
+                     "GameContentLinks": {"ItemAction": {"Data0": [1]}, "Achievement": {"Data0": [2]}}},
+                    {"ID": 5, "Name": "Fat Chocobo", "Icon": "/i/004000/004005.png", "GameContentLinks": null}
+                ]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let mut result: CharacterResult =
+            serde_json::from_str(include_str!("../../tests/fixtures/character.json")).unwrap();
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let error = client
+            .collection_report(&result, CollectionKind::Mounts)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::MissingSections(_)));
+
+        result.mounts = Some(
+            serde_json::from_str(
+                r#"[
+                    {"Icon": "https://xivapi.com/i/004000/004001.png", "Name": "Company Chocobo"},
+                    {"Icon": "https://img.finalfantasyxiv.com/lds/h/unicorn.png", "Name": "unicorn"},
+                    {"Icon": "", "Name": "Einhorn", "Name_de": "Einhorn"},
+                    {"Icon": "", "Name": "Sabotender Emperador"}
+                ]"#,
+            )
+            .unwrap(),
+        );
+
+        let report = client
+            .collection_report(&result, CollectionKind::Mounts)
+            .await?;
+
+        assert_eq!(report.total(), 3);
+        assert_eq!(report.owned.len(), 2);
+        assert!((report.percentage() - 200.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.missing[0].name, "Fat Chocobo");
+        assert_eq!(report.owned[1].sources, ["Achievement", "ItemAction"]);
+        assert_eq!(report.unmatched, ["Einhorn", "Sabotender Emperador"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use bitflags::bitflags;
+
+use super::{extended::ExtendedCharacter, Character, CharacterResult};
+
+bitflags! {
+    /// Additional sections that can be requested with a character lookup.
+    ///
+    /// Sections can be combined, e.g. `CharacterData::ACHIEVEMENTS | CharacterData::FRIENDS`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct CharacterData: u8 {
+        /// The character's achievements (`AC`).
+        const ACHIEVEMENTS = 1;
+        /// The character's friend list (`FR`).
+        const FRIENDS = 1 << 1;
+        /// The character's Free Company (`FC`).
+        const FREE_COMPANY = 1 << 2;
+        /// The members of the character's Free Company (`FCM`).
+        const FREE_COMPANY_MEMBERS = 1 << 3;
+        /// The character's mounts and minions (`MIMO`).
+        const MOUNTS_MINIONS = 1 << 4;
+        /// The character's PvP team (`PVP`).
+        const PVP_TEAM = 1 << 5;
+        /// The character's class and job levels (`CJ`).
+        const CLASS_JOBS = 1 << 6;
+    }
+}
+
+impl CharacterData {
+    /// The codes XIVAPI uses for the selected sections.
+    pub(crate) fn codes(self) -> Vec<&'static str> {
+        [
+            (CharacterData::ACHIEVEMENTS, "AC"),
+            (CharacterData::FRIENDS, "FR"),
+            (CharacterData::FREE_COMPANY, "FC"),
+            (CharacterData::FREE_COMPANY_MEMBERS, "FCM"),
+            (CharacterData::MOUNTS_MINIONS, "MIMO"),
+            (CharacterData::PVP_TEAM, "PVP"),
+            (CharacterData::CLASS_JOBS, "CJ"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.contains(*flag))
+        .map(|(_, code)| code)
+        .collect()
+    }
+}
+
+/// The parts of a character profile needed to tell which sections should be present.
+pub(crate) trait Membership {
+    fn free_company_id(&self) -> &str;
+    fn pvp_team_id(&self) -> Option<&str>;
+}
+
+impl Membership for Character {
+    fn free_company_id(&self) -> &str {
+        &self.free_company_id
+    }
+
+    fn pvp_team_id(&self) -> Option<&str> {
+        self.pvp_team_id.as_deref()
+    }
+}
+
+impl Membership for ExtendedCharacter {
+    fn free_company_id(&self) -> &str {
+        &self.free_company_id
+    }
+
+    fn pvp_team_id(&self) -> Option<&str> {
+        self.pvp_team_id.as_deref()
+    }
+}
+
+impl<C> CharacterResult<C> {
+    /// Returns the requested sections that are missing from the result.
+    ///
+    /// Sections that are legitimately empty aren't reported: private achievements and friend lists,
+    /// and the Free Company or PvP team of a character that isn't in one.
+    pub(crate) fn missing_sections(&self, requested: CharacterData) -> CharacterData
+    where
+        C: Membership,
+    {
+        let mut missing = CharacterData::empty();
+
+        if requested.contains(CharacterData::ACHIEVEMENTS)
+            && (self.achievements_public.is_none()
+                || (self.achievements_public == Some(true) && self.achievements.is_none()))
+        {
+            missing |= CharacterData::ACHIEVEMENTS;
+        }
+
+        if requested.contains(CharacterData::FRIENDS)
+            && (self.friends_public.is_none()
+                || (self.friends_public == Some(true) && self.friends.is_none()))
+        {
+            missing |= CharacterData::FRIENDS;
+        }
+
+        let in_free_company = !self.character.free_company_id().is_empty();
+
+        if requested.contains(CharacterData::FREE_COMPANY)
+            && in_free_company
+            && self.free_company.is_none()
+        {
+            missing |= CharacterData::FREE_COMPANY;
+        }
+
+        if requested.contains(CharacterData::FREE_COMPANY_MEMBERS)
+            && in_free_company
+            && self.free_company_members.is_none()
+        {
+            missing |= CharacterData::FREE_COMPANY_MEMBERS;
+        }
+
+        if requested.contains(CharacterData::MOUNTS_MINIONS)
+            && (self.mounts.is_none() || self.minions.is_none())
+        {
+            missing |= CharacterData::MOUNTS_MINIONS;
+        }
+
+        if requested.contains(CharacterData::PVP_TEAM)
+            && self.character.pvp_team_id().is_some()
+            && self.pvpteam.is_none()
+        {
+            missing |= CharacterData::PVP_TEAM;
+        }
+
+        missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharacterData;
+    use crate::character::CharacterResult;
+
+    #[test]
+    fn test_codes() {
+        let data = CharacterData::ACHIEVEMENTS
+            | CharacterData::FREE_COMPANY_MEMBERS
+            | CharacterData::MOUNTS_MINIONS;
+
+        assert_eq!(data.codes(), ["AC", "FCM", "MIMO"]);
+        assert!(CharacterData::empty().codes().is_empty());
+    }
+
+    #[test]
+    fn test_missing_sections() {
+        let mut result: CharacterResult =
+            serde_json::from_str(include_str!("../../tests/fixtures/character.json")).unwrap();
+
+        assert_eq!(
+            result.missing_sections(CharacterData::FRIENDS | CharacterData::PVP_TEAM),
+            CharacterData::FRIENDS
+        );
+
+        result.friends_public = Some(false);
+        assert!(result.missing_sections(CharacterData::FRIENDS).is_empty());
+
+        assert_eq!(
+            result.missing_sections(CharacterData::FREE_COMPANY),
+            CharacterData::FREE_COMPANY
+        );
+    }
+}
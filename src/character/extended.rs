@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    class::{ClassBozjan, ClassElemental, ClassUnlockedState},
+    CharacterResult,
+};
+use crate::LocalizedNames;
+
+/// All information obtained from a character ID request with `extended` enabled.
+pub type ExtendedCharacterResult = CharacterResult<ExtendedCharacter>;
+
+/// A detailed character profile, with IDs expanded into the objects they refer to.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedCharacter {
+    /// Detailed information about the character's active class job.
+    pub active_class_job: ExtendedClass,
+    /// The URL of the character's avatar.
+    pub avatar: String,
+    /// The character's biography.
+    pub bio: String,
+    /// List of class jobs the character has.
+    pub class_jobs: Vec<ExtendedClass>,
+    /// Class job information related to Bozjan content.
+    pub class_jobs_bozjan: ClassBozjan,
+    /// Class job information related to Elemental content.
+    pub class_jobs_elemental: ClassElemental,
+    /// The data center the character belongs to.
+    #[serde(rename = "DC")]
+    pub dc: String,
+    /// The ID of the Free Company the character belongs to.
+    pub free_company_id: String,
+    /// The name of the Free Company the character belongs to.
+    pub free_company_name: String,
+    /// Detailed information about the character's gear set.
+    pub gear_set: ExtendedGearSet,
+    /// The character's gender.
+    pub gender: u8,
+    /// Detailed information about the character's Grand Company affiliation.
+    pub grand_company: ExtendedGrandCompany,
+    /// The character's guardian deity.
+    pub guardian_deity: Option<GameObject>,
+    /// The ID of the character.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The language code associated with the character, if available.
+    pub lang: Option<String>,
+    /// The name of the character.
+    pub name: String,
+    /// The character's nameday.
+    pub nameday: String,
+    /// The parse date for the character's data.
+    pub parse_date: u32,
+    /// The URL of the character's portrait.
+    pub portrait: String,
+    /// The ID of the character's PvP Team, if available.
+    #[serde(rename = "PvPTeamId")]
+    pub pvp_team_id: Option<String>,
+    /// The character's race.
+    pub race: GameObject,
+    /// The name of the server the character belongs to.
+    pub server: String,
+    /// The character's title, if they have one.
+    pub title: Option<Title>,
+    /// Indicates if the character's title is at the top or bottom, if available.
+    pub title_top: bool,
+    /// The town the character started in.
+    pub town: GameObject,
+    /// The character's tribe, i.e. their clan.
+    pub tribe: GameObject,
+}
+
+/// A row of game data that an ID was expanded into, such as a town or a Grand Company.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GameObject {
+    /// The unique ID of the row.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The name of the row.
+    pub name: String,
+    /// The path of the row's icon, if it has one.
+    pub icon: Option<String>,
+    /// The name in each language, for any of the `Name_<lang>` fields XIVAPI included.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+}
+
+/// A character's title.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Title {
+    /// The unique ID of the title.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The masculine form of the title, if XIVAPI has it.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The feminine form of the title, if XIVAPI has it.
+    #[serde(default)]
+    pub name_female: Option<String>,
+    /// Indicates if the title is shown before the character's name.
+    #[serde(default)]
+    pub is_prefix: Option<u8>,
+    /// The path of the title's icon, if it has one.
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// A character's Grand Company information, with the company and rank expanded.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedGrandCompany {
+    /// The Grand Company the character belongs to, if any.
+    pub company: Option<GameObject>,
+    /// The character's rank in the Grand Company, if any.
+    pub rank: Option<GameObject>,
+}
+
+/// Class information, with the class and job expanded.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedClass {
+    /// The class.
+    pub class: ClassJob,
+    /// The job the class upgrades to, or the class itself if it has no job.
+    pub job: ClassJob,
+    /// The current experience level of the class.
+    pub exp_level: u32,
+    /// The maximum experience level of the class.
+    pub exp_level_max: u32,
+    /// The remaining experience points required to reach the next level.
+    pub exp_level_togo: u32,
+    /// Indicates if the class is a specialized job or not.
+    pub is_specialised: bool,
+    /// The current level of the class or job.
+    pub level: u32,
+    /// The name of the class.
+    pub name: String,
+    /// Information about the unlocked state of the class.
+    pub unlocked_state: ClassUnlockedState,
+}
+
+/// A class or job.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ClassJob {
+    /// The unique ID of the class or job.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The abbreviation of the class or job, e.g. `PLD`.
+    pub abbreviation: String,
+    /// The name of the class or job.
+    pub name: String,
+    /// The path of the class or job's icon.
+    pub icon: String,
+    /// The name of the class or job in each language, if XIVAPI included them.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+}
+
+/// A character's current gear set, with items expanded.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedGearSet {
+    /// A map of attribute IDs to their corresponding values for the gear set.
+    pub attributes: HashMap<u32, u32>,
+    /// The class associated with the gear set.
+    pub class: ClassJob,
+    /// The individual gear pieces equipped by the character.
+    pub gear: ExtendedGear,
+    /// The gear set's unique key identifier.
+    pub gear_key: String,
+    /// The job associated with the gear set, if applicable.
+    pub job: Option<ClassJob>,
+    /// The character's current level for the gear set.
+    pub level: u32,
+}
+
+/// Individual gear slots containing equipped gear pieces.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedGear {
+    /// The gear piece equipped in the body slot.
+    pub body: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the bracelets slot.
+    pub bracelets: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the earrings slot.
+    pub earrings: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the feet slot.
+    pub feet: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the hands slot.
+    pub hands: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the head slot.
+    pub head: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the legs slot.
+    pub legs: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the main hand slot.
+    pub main_hand: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the necklace slot.
+    pub necklace: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the off-hand slot.
+    pub off_hand: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the first ring slot.
+    pub ring1: Option<ExtendedGearPiece>,
+    /// The gear piece equipped in the second ring slot.
+    pub ring2: Option<ExtendedGearPiece>,
+}
+
+/// An individual gear piece, with the item, dye, glamour and materia expanded.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtendedGearPiece {
+    /// The name of the creator of the gear piece (if applicable).
+    pub creator: Option<String>,
+    /// The dye applied to the gear piece (if applicable).
+    pub dye: Option<ItemSummary>,
+    /// The item equipped in the slot.
+    pub item: ItemSummary,
+    /// The materia attached to the gear piece.
+    pub materia: Vec<ItemSummary>,
+    /// The item used as glamour for the gear piece (if applicable).
+    pub mirage: Option<ItemSummary>,
+}
+
+/// The summary of an item or dye that XIVAPI includes in extended character lookups.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ItemSummary {
+    /// The unique ID of the item.
+    #[serde(rename = "ID")]
+    pub id: u32,
+    /// The name of the item.
+    pub name: String,
+    /// The path of the item's icon, if it has one. Dyes have none.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// The item level, if the item is equipment.
+    #[serde(default)]
+    pub level_item: Option<u32>,
+    /// The level required to equip the item, if the item is equipment.
+    #[serde(default)]
+    pub level_equip: Option<u32>,
+    /// The rarity of the item.
+    #[serde(default)]
+    pub rarity: Option<u32>,
+    /// The name of the item in each language, if XIVAPI included them.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtendedCharacterResult, Title};
+    use crate::Language;
+
+    #[test]
+    fn test_extended_fixture() {
+        let result: ExtendedCharacterResult =
+            serde_json::from_str(include_str!("../../tests/fixtures/character_extended.json"))
+                .unwrap();
+
+        let character = result.character;
+        assert_eq!(character.active_class_job.job.abbreviation, "PLD");
+        assert_eq!(character.race.name, "Miqo'te");
+        assert_eq!(
+            character.title.unwrap().name.as_deref(),
+            Some("Warrior of Light")
+        );
+        let body = character.gear_set.gear.body.unwrap();
+        assert_eq!(body.item.id, 40191);
+        assert_eq!(body.item.icon.as_deref(), Some("/i/040191.png"));
+        assert_eq!(body.mirage.unwrap().id, 24589);
+        let dye = body.dye.unwrap();
+        assert_eq!(dye.icon, None);
+        assert_eq!(dye.level_item, None);
+        assert_eq!(dye.names.get(Language::German), Some("Ebenholzschwarz"));
+        assert_eq!(
+            character.grand_company.company.unwrap().name,
+            "Order of the Twin Adder"
+        );
+    }
+
+    #[test]
+    fn test_title_without_names() {
+        let title: Title =
+            serde_json::from_str(r#"{"ID": 0, "Name": null, "NameFemale": null}"#).unwrap();
+
+        assert_eq!(title.name, None);
+        assert_eq!(title.name_female, None);
+        assert_eq!(title.icon, None);
+    }
+}
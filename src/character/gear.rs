@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::{item::Item, Error, XIVAPIClient};
+
 /// A character's current gear set.
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "PascalCase")]
@@ -67,4 +69,34 @@ pub struct GearPiece {
     pub materia: Vec<u32>,
     /// The unique ID of the mirage applied to the gear piece (if applicable).
     pub mirage: Option<u32>,
-}
\ No newline at end of file
+}
+
+impl GearPiece {
+    /// Looks up the item the gear piece is, along with the item used as its glamour.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the items as `ResolvedGearPiece` or an `Error`
+    /// if a request to XIVAPI fails.
+    pub async fn resolve(&self, client: &XIVAPIClient) -> Result<ResolvedGearPiece, Error> {
+        let mirage = async {
+            match self.mirage.filter(|&id| id != 0) {
+                Some(id) => client.item(id).await.map(Some),
+                None => Ok(None),
+            }
+        };
+
+        let (item, mirage) = futures::try_join!(client.item(self.id), mirage)?;
+
+        Ok(ResolvedGearPiece { item, mirage })
+    }
+}
+
+/// A gear piece with its item IDs looked up on the `Item` sheet.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedGearPiece {
+    /// The item equipped in the slot.
+    pub item: Item,
+    /// The item used as glamour for the gear piece (if applicable).
+    pub mirage: Option<Item>,
+}
@@ -4,11 +4,22 @@ use serde::{Deserialize, Serialize};
 pub mod achievements;
 /// Module containing structures related to character classes.
 pub mod class;
+/// Module containing the mount and minion collections of characters.
+pub mod collection;
+/// Module containing the selectors for additional character lookup data.
+pub mod data;
+/// Module containing the expanded structures returned by extended character lookups.
+pub mod extended;
 /// Module containing structures related to character gear sets.
 pub mod gear;
+/// Module containing the states a character can be in on XIVAPI's side.
+pub mod state;
 
-use crate::{freecompany::FreeCompany, pagination::Pagination};
+use crate::{freecompany::FreeCompany, pagination::Pagination, pvpteam::PvPTeam, LocalizedNames};
 use achievements::CharacterAchievements;
+pub use data::CharacterData;
+pub use extended::ExtendedCharacterResult;
+pub use state::LookupState;
 
 use self::{
     class::{Class, ClassBozjan, ClassElemental},
@@ -20,7 +31,7 @@ use self::{
 #[serde(rename_all = "PascalCase")]
 pub struct CharacterSearchResults {
     /// Pagination information for the search results.
-    pagination: Pagination,
+    pub pagination: Pagination,
     /// List of characters that match the name search.
     pub results: Vec<CharacterSearch>,
 }
@@ -49,15 +60,17 @@ pub struct CharacterSearch {
 }
 
 /// All information obtained from a character ID request.
+///
+/// The profile is a `Character` by default, or an `ExtendedCharacter` for extended lookups.
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-pub struct CharacterResult {
+pub struct CharacterResult<C = Character> {
     /// Detailed information about the character's achievements, if available.
     pub achievements: Option<CharacterAchievements>,
     /// Indicates if the character's achievements are public or not.
     pub achievements_public: Option<bool>,
     /// Detailed information about the character.
-    pub character: Character,
+    pub character: C,
     /// Detailed information about the Free Company the character belongs to, if available.
     pub free_company: Option<FreeCompany>,
     /// List of members of the Free Company the character belongs to, if available.
@@ -70,9 +83,9 @@ pub struct CharacterResult {
     pub minions: Option<Vec<Mimo>>,
     /// List of the character's mounts, if available.
     pub mounts: Option<Vec<Mimo>>,
-    /// The ID of the character's PvP Team, if available.
+    /// The character's PvP team, if requested and the character is in one.
     #[serde(rename = "PvPTeam")]
-    pub pvpteam: Option<String>,
+    pub pvpteam: Option<PvPTeam>,
 }
 
 /// A detailed character profile.
@@ -153,4 +166,7 @@ pub struct Mimo {
     pub icon: String,
     /// The name of the mount or minion.
     pub name: String,
-}
\ No newline at end of file
+    /// The name in each language, for any of the `Name_<lang>` fields XIVAPI included.
+    #[serde(flatten)]
+    pub names: LocalizedNames,
+}
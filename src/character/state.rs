@@ -0,0 +1,386 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use super::CharacterResult;
+use crate::{error, Error, XIVAPIClient};
+
+/// How long `wait_until_fresh()` waits between polls.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The state of a character on XIVAPI's side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LookupState {
+    /// The character has been parsed and can be looked up.
+    Ready,
+    /// The character is being added or parsed from the Lodestone, so no data is available yet.
+    Pending,
+    /// The character's owner asked XIVAPI not to serve their character.
+    Blacklisted,
+    /// The character doesn't exist on the Lodestone.
+    NotFound,
+    /// The character's Lodestone profile is private.
+    Private,
+}
+
+impl LookupState {
+    /// Converts the numeric `Info.Character.State` XIVAPI includes with some responses.
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(LookupState::Pending),
+            2 => Some(LookupState::Ready),
+            3 => Some(LookupState::NotFound),
+            4 => Some(LookupState::Blacklisted),
+            5 => Some(LookupState::Private),
+            _ => None,
+        }
+    }
+
+    /// Works out the state from a successful character response.
+    pub(crate) fn from_response(status: StatusCode, body: &[u8]) -> Self {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Info {
+            info: CharacterInfo,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct CharacterInfo {
+            character: StateInfo,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct StateInfo {
+            state: u8,
+        }
+
+        let reported = serde_json::from_slice::<Info>(body)
+            .ok()
+            .and_then(|info| LookupState::from_code(info.info.character.state));
+
+        match reported {
+            Some(state) => state,
+            None if status == StatusCode::ACCEPTED => LookupState::Pending,
+            None => LookupState::Ready,
+        }
+    }
+
+    /// Works out the state from an error response, if the error describes one.
+    pub(crate) fn from_error(error: &Error) -> Option<Self> {
+        match error.status()? {
+            StatusCode::NOT_FOUND => Some(LookupState::NotFound),
+            StatusCode::FORBIDDEN => Some(LookupState::Private),
+            StatusCode::GONE | StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS => {
+                Some(LookupState::Blacklisted)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl XIVAPIClient {
+    /// Asks XIVAPI to parse a character from the Lodestone again.
+    ///
+    /// XIVAPI only accepts an update every few hours per character. Once the update is queued,
+    /// cached lookups of the character are removed, so the next lookup fetches the new data.
+    ///
+    /// # Arguments
+    ///
+    /// * `character_id` - The ID of the character to update.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the update was queued, `false` if XIVAPI declined it,
+    /// or an `Error` if the request to XIVAPI fails.
+    pub async fn request_character_update(&self, character_id: u32) -> Result<bool, Error> {
+        let url = self
+            .url(&["character", &character_id.to_string(), "update"])
+            .build();
+        let response = self.fetch_uncached(&url).await?;
+        let queued: serde_json::Value = error::decode(response.status, &url, &response.body)?;
+        let queued = queued == serde_json::Value::Bool(true) || queued.as_u64() == Some(1);
+
+        if queued {
+            self.invalidate_character(character_id).await?;
+        }
+
+        Ok(queued)
+    }
+
+    /// Checks whether XIVAPI can serve a character, bypassing the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `character_id` - The ID of the character to check.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the character's `LookupState` or an `Error` if the request to
+    /// XIVAPI fails for another reason.
+    pub async fn character_state(&self, character_id: u32) -> Result<LookupState, Error> {
+        let url = self.url(&["character", &character_id.to_string()]).build();
+
+        match self.fetch_uncached(&url).await {
+            Ok(response) => Ok(LookupState::from_response(response.status, &response.body)),
+            Err(error) => LookupState::from_error(&error).ok_or(error),
+        }
+    }
+
+    /// Polls a character until it has been parsed after `newer_than`.
+    ///
+    /// Usually called after `request_character_update()`. Polls bypass the cache, and cached
+    /// lookups of the character are removed once fresh data arrives.
+    ///
+    /// # Arguments
+    ///
+    /// * `character_id` - The ID of the character to wait for.
+    /// * `newer_than` - The time the character has to be parsed after, e.g. the time the update was requested.
+    /// * `timeout` - How long to keep polling for.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the fresh `CharacterResult`, `Error::UpdateTimeout` if the character
+    /// wasn't parsed in time, `Error::CharacterUnavailable` as soon as XIVAPI reports that the
+    /// character is private, blacklisted or doesn't exist, or another `Error` if a request to
+    /// XIVAPI fails.
+    pub async fn wait_until_fresh(
+        &self,
+        character_id: u32,
+        newer_than: SystemTime,
+        timeout: Duration,
+    ) -> Result<CharacterResult, Error> {
+        self.poll_until_fresh(character_id, newer_than, timeout, POLL_INTERVAL)
+            .await
+    }
+
+    async fn poll_until_fresh(
+        &self,
+        character_id: u32,
+        newer_than: SystemTime,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<CharacterResult, Error> {
+        // A timeout too long to represent means polling until the character is parsed.
+        let deadline = Instant::now().checked_add(timeout);
+        let newer_than = newer_than
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let url = self.url(&["character", &character_id.to_string()]).build();
+        let mut parse_date = None;
+
+        loop {
+            let response = self.fetch_uncached(&url).await?;
+
+            match LookupState::from_response(response.status, &response.body) {
+                LookupState::Ready => {
+                    let result: CharacterResult =
+                        error::decode(response.status, &url, &response.body)?;
+
+                    if u64::from(result.character.parse_date) > newer_than {
+                        self.invalidate_character(character_id).await?;
+                        return Ok(result);
+                    }
+
+                    parse_date = Some(result.character.parse_date);
+                }
+                LookupState::Pending => {}
+                state => return Err(Error::CharacterUnavailable(state)),
+            }
+
+            let wait = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+
+                    if now >= deadline {
+                        return Err(Error::UpdateTimeout { parse_date });
+                    }
+
+                    interval.min(deadline - now)
+                }
+                None => interval,
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use reqwest::StatusCode;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::LookupState;
+    use crate::{character::CharacterData, CacheConfig, Error, XIVAPIClient};
+
+    #[test]
+    fn test_state_from_response() {
+        assert_eq!(
+            LookupState::from_response(StatusCode::OK, br#"{"Character": {}}"#),
+            LookupState::Ready
+        );
+        assert_eq!(
+            LookupState::from_response(StatusCode::ACCEPTED, b""),
+            LookupState::Pending
+        );
+        assert_eq!(
+            LookupState::from_response(
+                StatusCode::OK,
+                br#"{"Info": {"Character": {"State": 5, "Updated": null}}}"#
+            ),
+            LookupState::Private
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_and_wait() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678/update"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("1"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(ResponseTemplate::new(202))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../tests/fixtures/character.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        assert!(client.request_character_update(12345678).await?);
+        assert_eq!(
+            client.character_state(12345678).await?,
+            LookupState::Pending
+        );
+
+        let result = client
+            .poll_until_fresh(
+                12345678,
+                UNIX_EPOCH + Duration::from_secs(1_600_000_000),
+                Duration::from_secs(5),
+                Duration::from_millis(10),
+            )
+            .await?;
+        assert_eq!(result.character.parse_date, 1_700_000_000);
+
+        let error = client
+            .poll_until_fresh(
+                12345678,
+                SystemTime::now(),
+                Duration::from_millis(50),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UpdateTimeout {
+                parse_date: Some(1_700_000_000)
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_declined_update_keeps_cache() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678/update"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678/update"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("0"))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../tests/fixtures/character.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .cache(CacheConfig::new())
+            .build()?;
+
+        client
+            .character_lookup(12345678, false, CharacterData::empty())
+            .await?;
+
+        assert!(client.request_character_update(12345678).await.is_err());
+        assert!(!client.request_character_update(12345678).await?);
+
+        client
+            .character_lookup(12345678, false, CharacterData::empty())
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_character_fails_fast() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"Info": {"Character": {"State": 5, "Updated": null}}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let error = client
+            .poll_until_fresh(
+                12345678,
+                SystemTime::now(),
+                Duration::MAX,
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::CharacterUnavailable(LookupState::Private)
+        ));
+
+        Ok(())
+    }
+}
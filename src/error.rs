@@ -0,0 +1,312 @@
+use std::fmt;
+
+use reqwest::{StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::character::{CharacterData, LookupState};
+
+/// The error object XIVAPI returns in place of the requested data when a query fails.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApiError {
+    /// Always `true` for error responses.
+    pub error: bool,
+    /// A short description of the kind of error.
+    pub subject: String,
+    /// A description of what went wrong.
+    pub message: String,
+    /// Additional information about the error, if available.
+    pub note: Option<String>,
+}
+
+/// Errors that can occur while querying XIVAPI.
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    Request(reqwest::Error),
+    /// XIVAPI answered with a non-success HTTP status and no error object.
+    Status {
+        /// The HTTP status of the response.
+        status: StatusCode,
+        /// The URL that was requested.
+        url: String,
+    },
+    /// XIVAPI answered with its structured error object.
+    Api {
+        /// The HTTP status of the response.
+        status: StatusCode,
+        /// The error object returned by XIVAPI.
+        error: ApiError,
+    },
+    /// The response body could not be deserialized into the expected type.
+    Decode {
+        /// The path to the field that failed to deserialize, e.g. `Character.GearSet.Gear`.
+        path: String,
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+    },
+    /// The client was configured with invalid or conflicting options.
+    Config(String),
+    /// A search was filtered by a world or data center that doesn't exist.
+    UnknownServer(String),
+    /// The list of worlds and data centers used to validate a search's `server` filter recently
+    /// failed to be requested, so the search wasn't sent.
+    ServerCatalogUnavailable(String),
+    /// XIVAPI didn't return some of the sections requested with a character lookup.
+    MissingSections(CharacterData),
+    /// XIVAPI can't serve the character, e.g. because its Lodestone profile is private.
+    CharacterUnavailable(LookupState),
+    /// `XIVAPIClient::wait_until_fresh()` timed out before the character was parsed again.
+    UpdateTimeout {
+        /// The parse date of the last profile received, if any was received.
+        parse_date: Option<u32>,
+    },
+    /// The on-disk cache couldn't be read or updated.
+    Cache(std::io::Error),
+    /// The client is in offline mode and no cached response exists for the request.
+    Offline {
+        /// The URL that would have been requested.
+        url: String,
+    },
+    /// The request was retried according to the client's `RetryPolicy`, but still failed.
+    Retried {
+        /// The number of attempts that were made, including the first one.
+        attempts: u32,
+        /// The error returned by the last attempt.
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns the HTTP status of the response that caused the error, if one was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Request(error) => error.status(),
+            Error::Status { status, .. } | Error::Api { status, .. } => Some(*status),
+            Error::Decode { .. }
+            | Error::Config(_)
+            | Error::UnknownServer(_)
+            | Error::ServerCatalogUnavailable(_)
+            | Error::MissingSections(_)
+            | Error::CharacterUnavailable(_)
+            | Error::UpdateTimeout { .. }
+            | Error::Cache(_)
+            | Error::Offline { .. } => None,
+            Error::Retried { source, .. } => source.status(),
+        }
+    }
+
+    /// Whether XIVAPI couldn't be reached or couldn't serve the request right now, as opposed to
+    /// rejecting the request itself.
+    pub(crate) fn is_unavailable(&self) -> bool {
+        match self {
+            Error::Request(_) => true,
+            Error::Retried { source, .. } => source.is_unavailable(),
+            error => error.is_server_error() || error.is_rate_limited(),
+        }
+    }
+
+    /// Returns the number of attempts made before giving up.
+    pub fn attempts(&self) -> u32 {
+        match self {
+            Error::Retried { attempts, .. } => *attempts,
+            _ => 1,
+        }
+    }
+
+    /// Returns `true` if the requested resource (e.g. a character or Free Company) does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns `true` if XIVAPI rejected the request because of rate limiting.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Returns `true` if XIVAPI failed with a server-side (5xx) error.
+    pub fn is_server_error(&self) -> bool {
+        self.status().is_some_and(|status| status.is_server_error())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(error) => write!(f, "request to XIVAPI failed: {}", error),
+            Error::Status { status, url } => write!(f, "XIVAPI returned {} for {}", status, url),
+            Error::Api { status, error } => {
+                write!(
+                    f,
+                    "XIVAPI returned {}: {}: {}",
+                    status, error.subject, error.message
+                )
+            }
+            Error::Decode { path, source } => {
+                write!(
+                    f,
+                    "failed to decode XIVAPI response at `{}`: {}",
+                    path, source
+                )
+            }
+            Error::Config(message) => write!(f, "invalid client configuration: {}", message),
+            Error::UnknownServer(server) => write!(f, "unknown world or data center `{}`", server),
+            Error::ServerCatalogUnavailable(reason) => write!(
+                f,
+                "can't validate the server filter, the list of worlds is unavailable: {}",
+                reason
+            ),
+            Error::MissingSections(sections) => write!(
+                f,
+                "XIVAPI response is missing the requested sections: {}",
+                sections.codes().join(", ")
+            ),
+            Error::CharacterUnavailable(state) => {
+                let reason = match state {
+                    LookupState::Ready => "it is ready",
+                    LookupState::Pending => "it is still being added",
+                    LookupState::Blacklisted => "its owner asked XIVAPI not to serve it",
+                    LookupState::NotFound => "it doesn't exist on the Lodestone",
+                    LookupState::Private => "its Lodestone profile is private",
+                };
+                write!(f, "XIVAPI can't serve the character, {}", reason)
+            }
+            Error::UpdateTimeout {
+                parse_date: Some(parse_date),
+            } => write!(
+                f,
+                "timed out waiting for the character to be parsed again (last parsed at {})",
+                parse_date
+            ),
+            Error::UpdateTimeout { parse_date: None } => {
+                write!(f, "timed out waiting for the character to be parsed")
+            }
+            Error::Cache(error) => write!(f, "can't update the disk cache: {}", error),
+            Error::Offline { url } => write!(f, "offline and no cached response for {}", url),
+            Error::Retried { attempts, source } => {
+                write!(f, "{} (after {} attempts)", source, attempts)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Decode { source, .. } => Some(source),
+            Error::Cache(error) => Some(error),
+            Error::Retried { source, .. } => Some(source.as_ref()),
+            Error::Status { .. }
+            | Error::Api { .. }
+            | Error::Config(_)
+            | Error::UnknownServer(_)
+            | Error::ServerCatalogUnavailable(_)
+            | Error::MissingSections(_)
+            | Error::CharacterUnavailable(_)
+            | Error::UpdateTimeout { .. }
+            | Error::Offline { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+/// Checks a raw XIVAPI response for errors without decoding its data.
+///
+/// XIVAPI's error object takes precedence over the HTTP status, since it carries the most useful message.
+pub(crate) fn check(status: StatusCode, url: &Url, body: &[u8]) -> Result<(), Error> {
+    if let Ok(error) = serde_json::from_slice::<ApiError>(body) {
+        if error.error {
+            return Err(Error::Api { status, error });
+        }
+    }
+
+    if !status.is_success() {
+        return Err(Error::Status {
+            status,
+            url: url.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Turns a raw XIVAPI response into either the expected type or an [`Error`].
+pub(crate) fn decode<T: DeserializeOwned>(
+    status: StatusCode,
+    url: &Url,
+    body: &[u8],
+) -> Result<T, Error> {
+    check(status, url, body)?;
+
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|error| Error::Decode {
+        path: error.path().to_string(),
+        source: error.into_inner(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{StatusCode, Url};
+    use serde::Deserialize;
+
+    use super::{decode, Error};
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "PascalCase")]
+    #[allow(dead_code)]
+    struct Outer {
+        inner: Inner,
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "PascalCase")]
+    #[allow(dead_code)]
+    struct Inner {
+        level: u32,
+    }
+
+    fn url() -> Url {
+        Url::parse("https://xivapi.com/character/1").unwrap()
+    }
+
+    #[test]
+    fn test_decode_api_error() {
+        let body = br#"{"Error":true,"Subject":"XIVAPI Service Error","Message":"Character not found","Note":null}"#;
+
+        let error = decode::<Outer>(StatusCode::NOT_FOUND, &url(), body).unwrap_err();
+
+        assert!(error.is_not_found());
+        match error {
+            Error::Api { error, .. } => assert_eq!(error.message, "Character not found"),
+            other => panic!("expected an API error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_status_error() {
+        let error = decode::<Outer>(StatusCode::BAD_GATEWAY, &url(), b"<html></html>").unwrap_err();
+
+        assert!(error.is_server_error());
+        assert!(matches!(error, Error::Status { .. }));
+    }
+
+    #[test]
+    fn test_decode_reports_path() {
+        let body = br#"{"Inner":{"Level":"fifty"}}"#;
+
+        let error = decode::<Outer>(StatusCode::OK, &url(), body).unwrap_err();
+
+        match error {
+            Error::Decode { path, .. } => assert_eq!(path, "Inner.Level"),
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+    }
+}
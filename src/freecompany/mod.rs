@@ -6,7 +6,7 @@ use crate::{character::CharacterSearch, pagination::Pagination};
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct FreeCompanySearchResults {
-    /// Pagination information fro the search resuts.
+    /// Pagination information for the search results.
     pub pagination: Pagination,
     /// List of Free Companies that match the search criteria.
     pub results: Vec<FreeCompanySearch>,
@@ -33,7 +33,7 @@ pub struct FreeCompanySearch {
 pub struct FreeCompanyResult {
     /// Information about the Free Company itself.
     pub free_company: FreeCompany,
-    /// Optional list of members belonging to the Free Company. Only returned if `extended` is set to `true` while calling `free_company_lookup`
+    /// Optional list of members belonging to the Free Company. Only returned if `FreeCompanyLookupRequest::extended()` is enabled
     pub free_company_members: Option<Vec<CharacterSearch>>,
 }
 
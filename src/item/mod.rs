@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    character::extended::GameObject, request::SheetRowRequest, serde_helpers, Error,
+    LocalizedNames, XIVAPIClient,
+};
+
+/// The number of `BaseParam<n>` columns on the `Item` sheet.
+const BASE_PARAM_COUNT: usize = 6;
+
+/// An item from the `Item` sheet.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Item {
+    /// The unique ID of the item.
+    pub id: u32,
+    /// The name of the item.
+    pub name: String,
+    /// The name of the item in each language, if XIVAPI included them.
+    pub names: LocalizedNames,
+    /// The path of the item's icon.
+    pub icon: String,
+    /// The item level.
+    pub level_item: u32,
+    /// The level required to equip the item, or 0 if it can't be equipped.
+    pub level_equip: u32,
+    /// The rarity of the item, from 1 (common, white) to 7 (aetherial, pink).
+    pub rarity: u8,
+    /// The ID of the `EquipSlotCategory` the item is equipped in, if it can be equipped.
+    pub equip_slot_category: Option<u32>,
+    /// The classes and jobs that can equip the item, if it can be equipped.
+    pub class_job_category: Option<GameObject>,
+    /// The stats the item grants.
+    pub base_params: Vec<BaseParam>,
+    /// The number of materia that can be attached to the item without overmelding.
+    pub materia_slot_count: u8,
+    /// True if the item has a high quality version.
+    pub can_be_hq: bool,
+    /// The ID of the patch the item was added in, if it is known.
+    ///
+    /// Use `XIVAPIClient::patch()` to resolve it.
+    pub patch: Option<u32>,
+}
+
+/// A stat granted by an item.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct BaseParam {
+    /// The ID of the stat on the `BaseParam` sheet.
+    pub id: u32,
+    /// The name of the stat, e.g. `Critical Hit`.
+    pub name: String,
+    /// The amount of the stat granted.
+    pub value: i32,
+}
+
+/// An item as XIVAPI returns it.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawItem {
+    #[serde(rename = "ID")]
+    id: u32,
+    name: String,
+    #[serde(flatten)]
+    names: LocalizedNames,
+    #[serde(default)]
+    icon: String,
+    #[serde(default)]
+    level_item: Option<RowId>,
+    #[serde(default)]
+    level_equip: u32,
+    #[serde(default)]
+    rarity: u8,
+    #[serde(rename = "EquipSlotCategoryTargetID", default)]
+    equip_slot_category: Option<u32>,
+    #[serde(default)]
+    class_job_category: Option<GameObject>,
+    #[serde(default)]
+    materia_slot_count: u8,
+    #[serde(rename = "CanBeHq", default, deserialize_with = "serde_helpers::flag")]
+    can_be_hq: bool,
+    #[serde(default)]
+    game_patch: Option<RowId>,
+    #[serde(flatten)]
+    other: HashMap<String, serde_json::Value>,
+}
+
+/// A column that is either an ID or the row the ID was expanded into.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RowId {
+    Id(u32),
+    Row {
+        #[serde(rename = "ID")]
+        id: u32,
+    },
+}
+
+impl RowId {
+    fn id(&self) -> u32 {
+        match *self {
+            RowId::Id(id) | RowId::Row { id } => id,
+        }
+    }
+}
+
+impl From<RawItem> for Item {
+    fn from(raw: RawItem) -> Self {
+        let base_params = (0..BASE_PARAM_COUNT)
+            .filter_map(|index| {
+                let param: GameObject =
+                    serde_json::from_value(raw.other.get(&format!("BaseParam{index}"))?.clone())
+                        .ok()?;
+                let value = raw.other.get(&format!("BaseParamValue{index}"))?.as_i64()?;
+
+                (param.id != 0 && value != 0).then_some(BaseParam {
+                    id: param.id,
+                    name: param.name,
+                    value: i32::try_from(value).ok()?,
+                })
+            })
+            .collect();
+
+        Item {
+            id: raw.id,
+            name: raw.name,
+            names: raw.names,
+            icon: raw.icon,
+            level_item: raw.level_item.map_or(0, |level| level.id()),
+            level_equip: raw.level_equip,
+            rarity: raw.rarity,
+            equip_slot_category: raw.equip_slot_category.filter(|&id| id != 0),
+            class_job_category: raw.class_job_category.filter(|category| category.id != 0),
+            base_params,
+            materia_slot_count: raw.materia_slot_count,
+            can_be_hq: raw.can_be_hq,
+            patch: raw.game_patch.map(|patch| patch.id()),
+        }
+    }
+}
+
+/// The columns requested for items, since the `Item` sheet has several hundred.
+fn columns() -> Vec<String> {
+    let mut columns: Vec<String> = [
+        "ID",
+        "Name",
+        "Name_en",
+        "Name_ja",
+        "Name_de",
+        "Name_fr",
+        "Icon",
+        "LevelItem",
+        "LevelEquip",
+        "Rarity",
+        "EquipSlotCategoryTargetID",
+        "ClassJobCategory.ID",
+        "ClassJobCategory.Name",
+        "MateriaSlotCount",
+        "CanBeHq",
+        "GamePatch.ID",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    for index in 0..BASE_PARAM_COUNT {
+        columns.push(format!("BaseParam{index}.ID"));
+        columns.push(format!("BaseParam{index}.Name"));
+        columns.push(format!("BaseParamValue{index}"));
+    }
+
+    columns
+}
+
+impl XIVAPIClient {
+    /// Gets an item from the `Item` sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the item, e.g. `GearPiece::id`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Item` or an `Error` if the request to XIVAPI fails.
+    pub async fn item(&self, id: u32) -> Result<Item, Error> {
+        let item: RawItem = SheetRowRequest::new("Item", id)
+            .columns(columns())
+            .send(self)
+            .await?;
+
+        Ok(item.into())
+    }
+
+    /// Gets several items from the `Item` sheet, in as few requests as possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the items.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the items as `Vec<Item>`, leaving out IDs that don't exist, or an
+    /// `Error` if a request to XIVAPI fails.
+    pub async fn items(&self, ids: &[u32]) -> Result<Vec<Item>, Error> {
+        let items: Vec<RawItem> = self.sheet_rows_by_ids("Item", ids, &columns()).await?;
+
+        Ok(items.into_iter().map(Item::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{character::gear::GearPiece, sheet::tests::sheet_page, Error, XIVAPIClient};
+
+    const ITEM: &str = r#"{
+        "ID": 36942, "Name": "Diadochos Helm of Fending", "Icon": "/i/040000/040001.png",
+        "LevelItem": 590, "LevelEquip": 90, "Rarity": 4, "EquipSlotCategoryTargetID": 3,
+        "ClassJobCategory": {"ID": 59, "Name": "PLD WAR DRK GNB"},
+        "BaseParam0": {"ID": 1, "Name": "Strength"}, "BaseParamValue0": 287,
+        "BaseParam1": {"ID": 3, "Name": "Vitality"}, "BaseParamValue1": 315,
+        "BaseParam2": null, "BaseParamValue2": 0,
+        "MateriaSlotCount": 2, "CanBeHq": 0, "GamePatch": {"ID": 86}
+    }"#;
+
+    const GLAMOUR: &str = r#"{
+        "ID": 2901, "Name": "Eternal Eye", "Icon": "/i/040000/040002.png",
+        "LevelItem": {"ID": 1}, "LevelEquip": 1, "Rarity": 2, "EquipSlotCategoryTargetID": 3,
+        "ClassJobCategory": {"ID": 1, "Name": "All Classes"},
+        "MateriaSlotCount": 0, "CanBeHq": true, "GamePatch": {"ID": 2}
+    }"#;
+
+    #[tokio::test]
+    async fn test_items() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/Item/36942"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(ITEM))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/Item/2901"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(GLAMOUR))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/Item"))
+            .and(query_param("ids", "36942,2901"))
+            .respond_with(sheet_page(1, 1, &format!("[{ITEM}, {GLAMOUR}]")))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let item = client.item(36942).await?;
+        assert_eq!(item.level_item, 590);
+        assert_eq!(item.equip_slot_category, Some(3));
+        assert_eq!(item.base_params.len(), 2);
+        assert_eq!(item.base_params[1].name, "Vitality");
+        assert_eq!(item.patch, Some(86));
+        assert!(!item.can_be_hq);
+
+        let items = client.items(&[36942, 2901]).await?;
+        assert_eq!(items[1].level_item, 1);
+        assert!(items[1].can_be_hq);
+
+        let piece: GearPiece = serde_json::from_str(
+            r#"{"Creator": null, "Dye": null, "ID": 36942, "Materia": [], "Mirage": 2901}"#,
+        )
+        .unwrap();
+        let resolved = piece.resolve(&client).await?;
+        assert_eq!(resolved.item, item);
+        assert_eq!(resolved.mirage.unwrap().name, "Eternal Eye");
+
+        Ok(())
+    }
+}
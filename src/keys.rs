@@ -0,0 +1,183 @@
+use std::{
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use reqwest::Url;
+
+use crate::ratelimit::MAX_WAIT;
+
+/// The environment variable read by `XIVAPIClientBuilder::private_key_from_env()`.
+pub(crate) const PRIVATE_KEY_ENV: &str = "XIVAPI_KEY";
+
+/// The query parameter XIVAPI reads the private key from.
+pub(crate) const PRIVATE_KEY_PARAM: &str = "private_key";
+
+/// How long a key is skipped after being rate limited, if XIVAPI doesn't say otherwise.
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(60);
+
+/// An XIVAPI private key. Never printed in `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct PrivateKey(String);
+
+impl PrivateKey {
+    pub(crate) fn new(key: impl Into<String>) -> Self {
+        PrivateKey(key.into())
+    }
+
+    pub(crate) fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrivateKey(<redacted>)")
+    }
+}
+
+/// A set of private keys that rotates to the next key when one gets rate limited.
+pub(crate) struct KeyPool {
+    keys: Vec<PrivateKey>,
+    state: Mutex<PoolState>,
+}
+
+struct PoolState {
+    current: usize,
+    throttled_until: Vec<Option<Instant>>,
+}
+
+impl KeyPool {
+    /// Creates a pool from a non-empty list of keys.
+    pub(crate) fn new(keys: Vec<PrivateKey>) -> Self {
+        let throttled_until = vec![None; keys.len()];
+
+        KeyPool {
+            keys,
+            state: Mutex::new(PoolState {
+                current: 0,
+                throttled_until,
+            }),
+        }
+    }
+
+    /// Returns the key that should be used for the next request, along with its index in the pool.
+    ///
+    /// Keys that are still throttled are skipped. If every key is throttled, the one that
+    /// recovers first is returned.
+    pub(crate) fn next(&self) -> (usize, PrivateKey) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let len = self.keys.len();
+
+        let available = (0..len)
+            .map(|offset| (state.current + offset) % len)
+            .find(|&index| state.throttled_until[index].is_none_or(|until| until <= now));
+
+        let index = available.unwrap_or_else(|| {
+            (0..len)
+                .min_by_key(|&index| state.throttled_until[index])
+                .unwrap_or(0)
+        });
+
+        state.current = index;
+
+        (index, self.keys[index].clone())
+    }
+
+    /// Marks a key as rate limited and moves on to the next one.
+    ///
+    /// Returns `true` if another key is currently available to retry the request with.
+    pub(crate) fn throttle(&self, index: usize, duration: Option<Duration>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let len = self.keys.len();
+
+        // The duration comes from response headers, so it is capped rather than trusted.
+        let duration = duration.unwrap_or(DEFAULT_THROTTLE).min(MAX_WAIT);
+        state.throttled_until[index] = now.checked_add(duration);
+        state.current = (index + 1) % len;
+
+        state
+            .throttled_until
+            .iter()
+            .any(|until| until.is_none_or(|until| until <= now))
+    }
+}
+
+impl fmt::Debug for KeyPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPool")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+/// Replaces the private key in a URL so it can be safely shown in errors.
+pub(crate) fn redact(url: &mut Url) {
+    if !url.query_pairs().any(|(name, _)| name == PRIVATE_KEY_PARAM) {
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            if name == PRIVATE_KEY_PARAM {
+                (name.into_owned(), "<redacted>".to_string())
+            } else {
+                (name.into_owned(), value.into_owned())
+            }
+        })
+        .collect();
+
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
+
+/// Removes the private key from the URL attached to a `reqwest::Error`.
+pub(crate) fn redact_error(mut error: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = error.url_mut() {
+        redact(url);
+    }
+
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Url;
+
+    use super::{redact, KeyPool, PrivateKey};
+
+    #[test]
+    fn test_key_pool_rotates_when_throttled() {
+        let pool = KeyPool::new(vec![PrivateKey::new("first"), PrivateKey::new("second")]);
+
+        let (index, key) = pool.next();
+        assert_eq!(key.expose(), "first");
+
+        assert!(pool.throttle(index, Some(Duration::from_secs(60))));
+        assert_eq!(pool.next().1.expose(), "second");
+
+        assert!(!pool.throttle(1, Some(Duration::from_secs(30))));
+        assert_eq!(pool.next().1.expose(), "second");
+
+        // Headers can ask for any wait, which must not overflow while the pool is locked.
+        assert!(!pool.throttle(1, Some(Duration::MAX)));
+        assert_eq!(pool.next().1.expose(), "first");
+    }
+
+    #[test]
+    fn test_redact() {
+        let mut url =
+            Url::parse("https://xivapi.com/character/1?data=AC&private_key=secret").unwrap();
+
+        redact(&mut url);
+
+        assert!(!url.as_str().contains("secret"));
+        assert!(url.as_str().contains("data=AC"));
+        assert!(!format!("{:?}", PrivateKey::new("secret")).contains("secret"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// A language XIVAPI can localize responses in.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// English (`en`).
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    /// Japanese (`ja`).
+    #[serde(rename = "ja")]
+    Japanese,
+    /// German (`de`).
+    #[serde(rename = "de")]
+    German,
+    /// French (`fr`).
+    #[serde(rename = "fr")]
+    French,
+}
+
+impl Language {
+    /// Every supported language.
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::Japanese,
+        Language::German,
+        Language::French,
+    ];
+
+    /// The code XIVAPI uses for the language, e.g. `en`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Japanese => "ja",
+            Language::German => "de",
+            Language::French => "fr",
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The `Name_en`, `Name_ja`, `Name_de` and `Name_fr` fields XIVAPI adds to game data.
+///
+/// Each field is only present if XIVAPI included it in the response.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocalizedNames {
+    /// The English name.
+    #[serde(rename = "Name_en", default, skip_serializing_if = "Option::is_none")]
+    pub en: Option<String>,
+    /// The Japanese name.
+    #[serde(rename = "Name_ja", default, skip_serializing_if = "Option::is_none")]
+    pub ja: Option<String>,
+    /// The German name.
+    #[serde(rename = "Name_de", default, skip_serializing_if = "Option::is_none")]
+    pub de: Option<String>,
+    /// The French name.
+    #[serde(rename = "Name_fr", default, skip_serializing_if = "Option::is_none")]
+    pub fr: Option<String>,
+}
+
+impl LocalizedNames {
+    /// Returns the name in the given language, if it was included in the response.
+    pub fn get(&self, language: Language) -> Option<&str> {
+        match language {
+            Language::English => self.en.as_deref(),
+            Language::Japanese => self.ja.as_deref(),
+            Language::German => self.de.as_deref(),
+            Language::French => self.fr.as_deref(),
+        }
+    }
+
+    /// Returns true if none of the names were included in the response.
+    pub fn is_empty(&self) -> bool {
+        Language::ALL
+            .into_iter()
+            .all(|language| self.get(language).is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Language, LocalizedNames};
+
+    #[test]
+    fn test_localized_names() {
+        let names: LocalizedNames = serde_json::from_str(
+            r#"{"Name": "Wind-up Airship", "Name_en": "Wind-up Airship", "Name_ja": "飛空艇"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(names.get(Language::Japanese), Some("飛空艇"));
+        assert_eq!(names.get(Language::French), None);
+        assert!(!names.is_empty());
+        assert!(LocalizedNames::default().is_empty());
+    }
+}
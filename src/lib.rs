@@ -5,89 +5,91 @@
 //! [XIVAPI]: https://xivapi.com
 //! [here]: https://xivapi.com/docs
 
-
 #![warn(missing_docs)]
 
-use freecompany::{FreeCompanyResult, FreeCompanySearchResults};
-use reqwest::Client;
+use freecompany::{FreeCompanyResult, FreeCompanySearch, FreeCompanySearchResults};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use cache::{CachedResponse, DiskCache, Endpoint, MemoryCache};
+use futures::stream::BoxStream;
+use keys::KeyPool;
+use linkshell::{LinkshellResult, LinkshellSearchResults};
+use patch::PatchCatalog;
+use pvpteam::{PvPTeamResult, PvPTeamSearchResults};
+use query::RequestUrl;
+use ratelimit::RateLimiter;
+use request::{
+    CharacterLookupRequest, CharacterSearchRequest, FreeCompanyLookupRequest,
+    FreeCompanySearchRequest, LinkshellLookupRequest, LinkshellSearchRequest, PvPTeamLookupRequest,
+    PvPTeamSearchRequest,
+};
+use reqwest::{Client, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use world::ServerCatalog;
 
 /// Structs and modules used in character searches.
 pub mod character;
 /// Structs used to parse FC information.
 pub mod freecompany;
+/// Typed items from the `Item` sheet.
+pub mod item;
+/// Structs used to parse linkshell and cross-world linkshell information.
+pub mod linkshell;
+/// Lodestone news feeds and maintenance announcements.
+pub mod lodestone;
+/// Pagination information for search results.
+pub mod pagination;
+/// Game patches and expansions.
+pub mod patch;
+/// Structs used to parse PvP team information.
+pub mod pvpteam;
+/// Typed request builders for every endpoint.
+pub mod request;
+/// Generic access to game data sheets, such as `Item` or `Mount`.
+pub mod sheet;
+/// Verification of character ownership through Lodestone bios.
+pub mod verification;
+/// Worlds, data centers and regions.
+pub mod world;
 
-mod pagination;
-
-use character::{CharacterResult, CharacterSearchResults};
-
-#[cfg(test)]
-mod tests {
-    use crate::XIVAPIClient;
-
-    #[tokio::test]
-    async fn test_character_search() -> Result<(), reqwest::Error> {
-        let client = XIVAPIClient::new();
-
-        client
-            .character_search("Tami Pesagniyah", Some("Omega"), None)
-            .await?;
-
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn test_character_lookup() -> Result<(), reqwest::Error> {
-        let client = XIVAPIClient::new();
-
-        let result = client
-            .character_search("Tami Pesagniyah", Some("Omega"), None)
-            .await?;
-
-        client
-            .character_lookup(result.results[0].id, false, None)
-            .await?;
-
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn test_free_company_search() -> Result<(), reqwest::Error> {
-        let client = XIVAPIClient::new();
-
-        client
-            .free_company_search("SEES", Some("Omega"), None)
-            .await?;
-
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn test_free_company_lookup() -> Result<(), reqwest::Error> {
-        let client = XIVAPIClient::new();
-
-        let result = client
-            .free_company_search("SEES", Some("Omega"), None)
-            .await
-            .unwrap();
+mod builder;
+mod cache;
+mod error;
+mod keys;
+mod language;
+mod query;
+mod ratelimit;
+mod retry;
+mod serde_helpers;
 
-        client
-            .free_company_lookup(&result.results[0].id, false, None)
-            .await?;
+pub use builder::XIVAPIClientBuilder;
+pub use cache::{CacheConfig, Cached, Freshness};
+pub use error::{ApiError, Error};
+pub use language::{Language, LocalizedNames};
+pub use ratelimit::RateLimit;
+pub use retry::{Jitter, RetryPolicy};
 
-        Ok(())
-    }
-}
+use character::{
+    CharacterData, CharacterResult, CharacterSearch, CharacterSearchResults,
+    ExtendedCharacterResult,
+};
 
 /// The main client. Responsible for running all API queries.
 ///
-/// You must create a new client using `XIVAPIClient::new()` before you can make API calls.
+/// You must create a new client using `XIVAPIClient::new()` or `XIVAPIClient::builder()` before you can make API calls.
+///
+/// Requests with many options are easier to read using the builders in the [`request`] module.
 ///
 /// # Examples
-/// ```
+/// ```no_run
 /// use xivapi_rust::XIVAPIClient;
 ///
 /// #[tokio::main]
-/// async fn main() -> Result<(), reqwest::Error> {
+/// async fn main() -> Result<(), xivapi_rust::Error> {
 ///     let client = XIVAPIClient::new();
 ///
 ///     // Queries XIVAPI for a character named "Scott" on the Omega server.
@@ -97,15 +99,46 @@ mod tests {
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone, Debug)]
 pub struct XIVAPIClient {
     client: Client,
+    base_url: Url,
+    keys: Option<Arc<KeyPool>>,
+    limiter: Option<Arc<RateLimiter>>,
+    retry: Option<RetryPolicy>,
+    cache: Option<Arc<MemoryCache>>,
+    disk: Option<Arc<DiskCache>>,
+    stale_if_error: bool,
+    offline: bool,
+    language: Option<Language>,
+    servers: Option<Arc<ServerCatalog>>,
+    patches: Arc<PatchCatalog>,
 }
 
 impl XIVAPIClient {
     /// Creates a new instance of XIVAPIClient.
     pub fn new() -> Self {
         let client = Client::new();
-        XIVAPIClient { client }
+        let base_url = Url::parse(builder::DEFAULT_BASE_URL).expect("default base URL is valid");
+        XIVAPIClient {
+            client,
+            base_url,
+            keys: None,
+            limiter: None,
+            retry: None,
+            cache: None,
+            disk: None,
+            stale_if_error: false,
+            offline: false,
+            language: None,
+            servers: Some(Arc::default()),
+            patches: Arc::default(),
+        }
+    }
+
+    /// Creates a builder to configure the base URL, private keys, rate limiting, retries, caching, language, timeouts, user agent, proxy or HTTP client.
+    pub fn builder() -> XIVAPIClientBuilder {
+        XIVAPIClientBuilder::new()
     }
 
     /// Does a name-based search for a character.
@@ -116,82 +149,152 @@ impl XIVAPIClient {
     /// # Arguments
     ///
     /// * `name` - The name of the character to search for.
-    /// * `server` - An optional server name filter for the character search.
+    /// * `server` - An optional server name filter for the character search, or `_dc_<Name>`
+    ///   to search every world of a data center.
     /// * `page` - An optional page number for paginated results.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the search results as `CharacterSearchResults` or a `reqwest::Error`
+    /// A `Result` containing the search results as `CharacterSearchResults` or an `Error`
     /// if the request to XIVAPI fails.
     pub async fn character_search(
         &self,
         name: &str,
         server: Option<&str>,
-        page: Option<u8>,
-    ) -> Result<CharacterSearchResults, reqwest::Error> {
-        let mut search_params = Vec::new();
-
-        let name = name.split_whitespace().collect::<Vec<&str>>().join("+");
-        search_params.push(format!("name={}", name));
+        page: Option<u32>,
+    ) -> Result<CharacterSearchResults, Error> {
+        let mut request = CharacterSearchRequest::new(name);
 
         if let Some(server) = server {
-            search_params.push(format!("server={}", server))
+            request = request.server(server);
         }
 
         if let Some(page) = page {
-            search_params.push(format!("page={}", page))
+            request = request.page(page);
         }
 
-        let search_string = format!(
-            "https://xivapi.com/character/search?{}",
-            search_params.join("&")
-        );
+        request.send(self).await
+    }
+
+    /// Does a name-based search for a character, following every page of the results.
+    ///
+    /// The next page is requested in the background while the current one is being consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the character to search for.
+    /// * `server` - An optional server name filter for the character search.
+    /// * `max_pages` - An optional limit on the number of pages requested.
+    ///
+    /// # Returns
+    ///
+    /// A `Stream` of the matching characters. It ends after the last page, or after the first `Error`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use xivapi_rust::XIVAPIClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), xivapi_rust::Error> {
+    ///     let client = XIVAPIClient::new();
+    ///     let mut results = client.character_search_all("Scott", Some("Omega"), None);
+    ///
+    ///     while let Some(result) = results.next().await {
+    ///         println!("{}", result?.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn character_search_all(
+        &self,
+        name: &str,
+        server: Option<&str>,
+        max_pages: Option<u32>,
+    ) -> BoxStream<'static, Result<CharacterSearch, Error>> {
+        let mut request = CharacterSearchRequest::new(name);
+
+        if let Some(server) = server {
+            request = request.server(server);
+        }
 
-        let result: CharacterSearchResults =
-            self.client.get(search_string).send().await?.json().await?;
+        if let Some(max_pages) = max_pages {
+            request = request.max_pages(max_pages);
+        }
 
-        Ok(result)
+        request.stream(self)
     }
 
     /// Gives detailed information about a character from a character ID.
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `character_id` - The ID of the character to look up.
-    /// * `extended` - If true, extends out the data IDs of useful objects.
-    /// * `data` - Additional information to be requested from the API.
-    /// 
+    /// * `extended` - If true, extends out the data IDs of useful objects. Use
+    ///   `character_lookup_extended` to decode the expanded objects.
+    /// * `data` - Additional sections to be requested from the API, e.g. `CharacterData::FRIENDS`.
+    ///   Use `CharacterData::empty()` to request none.
+    ///
     /// # Returns
     ///
-    /// A `Result` containing character information as `CharacterResult` or a `reqwest::Error`
+    /// A `Result` containing character information as `CharacterResult` or an `Error`
     /// if the request to XIVAPI fails.
     pub async fn character_lookup(
         &self,
         character_id: u32,
         extended: bool,
-        data: Option<Vec<&str>>,
-    ) -> Result<CharacterResult, reqwest::Error> {
-        let mut seach_params = Vec::new();
-
-        if extended {
-            seach_params.push("extended=1".to_string())
-        }
-
-        if let Some(data) = data {
-            let data = data.join(",");
-
-            seach_params.push(format!("data={}", data))
-        }
-
-        let search_string = format!(
-            "https://xivapi.com/character/{}?{}",
-            character_id,
-            seach_params.join("&")
-        );
+        data: CharacterData,
+    ) -> Result<CharacterResult, Error> {
+        self.character_lookup_cached(character_id, extended, data)
+            .await
+            .map(Cached::into_inner)
+    }
 
-        let result: CharacterResult = self.client.get(search_string).send().await?.json().await?;
+    /// Same as `character_lookup`, but also reports whether the result came from the cache.
+    ///
+    /// With `XIVAPIClientBuilder::stale_if_error()` or `XIVAPIClientBuilder::offline()`, the result may
+    /// be an out of date copy of the character, which is marked as stale.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing character information as `Cached<CharacterResult>` or an `Error`
+    /// if the request to XIVAPI fails and no usable cached response exists.
+    pub async fn character_lookup_cached(
+        &self,
+        character_id: u32,
+        extended: bool,
+        data: CharacterData,
+    ) -> Result<Cached<CharacterResult>, Error> {
+        CharacterLookupRequest::new(character_id)
+            .extended(extended)
+            .data(data)
+            .send_cached(self)
+            .await
+    }
 
-        Ok(result)
+    /// Gives detailed information about a character from a character ID, with the IDs of useful
+    /// objects such as classes, items, titles and races expanded into the objects themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `character_id` - The ID of the character to look up.
+    /// * `data` - Additional sections to be requested from the API, e.g. `CharacterData::FRIENDS`.
+    ///   Use `CharacterData::empty()` to request none.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing character information as `ExtendedCharacterResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn character_lookup_extended(
+        &self,
+        character_id: u32,
+        data: CharacterData,
+    ) -> Result<ExtendedCharacterResult, Error> {
+        CharacterLookupRequest::new(character_id)
+            .data(data)
+            .send_extended(self)
+            .await
     }
 
     /// Does a name-based search for a Free Company (FC).
@@ -202,41 +305,81 @@ impl XIVAPIClient {
     /// # Arguments
     ///
     /// * `name` - The name of the Free Company to search for.
-    /// * `server` - An optional server name filter for the Free Company search.
+    /// * `server` - An optional server name filter for the Free Company search, or `_dc_<Name>`
+    ///   to search every world of a data center.
     /// * `page` - An optional page number for paginated results.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the search results as `FreeCompanySearchResults` or a `reqwest::Error`
+    /// A `Result` containing the search results as `FreeCompanySearchResults` or an `Error`
     /// if the request to XIVAPI fails.
     pub async fn free_company_search(
         &self,
         name: &str,
         server: Option<&str>,
-        page: Option<u8>,
-    ) -> Result<FreeCompanySearchResults, reqwest::Error> {
-        let mut search_params = Vec::new();
-
-        let name = name.split_whitespace().collect::<Vec<&str>>().join("+");
-        search_params.push(format!("name={}", name));
+        page: Option<u32>,
+    ) -> Result<FreeCompanySearchResults, Error> {
+        let mut request = FreeCompanySearchRequest::new(name);
 
         if let Some(server) = server {
-            search_params.push(format!("server={}", server))
+            request = request.server(server);
         }
 
         if let Some(page) = page {
-            search_params.push(format!("page={}", page))
+            request = request.page(page);
         }
 
-        let search_string = format!(
-            "https://xivapi.com/freecompany/search?{}",
-            search_params.join("&")
-        );
+        request.send(self).await
+    }
+
+    /// Does a name-based search for a Free Company, following every page of the results.
+    ///
+    /// The next page is requested in the background while the current one is being consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Free Company to search for.
+    /// * `server` - An optional server name filter for the Free Company search.
+    /// * `max_pages` - An optional limit on the number of pages requested.
+    ///
+    /// # Returns
+    ///
+    /// A `Stream` of the matching Free Companies. It ends after the last page, or after the first `Error`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use xivapi_rust::XIVAPIClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), xivapi_rust::Error> {
+    ///     let client = XIVAPIClient::new();
+    ///     let mut results = client.free_company_search_all("Tea & Biscuits", Some("Phoenix"), None);
+    ///
+    ///     while let Some(result) = results.next().await {
+    ///         println!("{}", result?.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn free_company_search_all(
+        &self,
+        name: &str,
+        server: Option<&str>,
+        max_pages: Option<u32>,
+    ) -> BoxStream<'static, Result<FreeCompanySearch, Error>> {
+        let mut request = FreeCompanySearchRequest::new(name);
+
+        if let Some(server) = server {
+            request = request.server(server);
+        }
 
-        let result: FreeCompanySearchResults =
-            self.client.get(search_string).send().await?.json().await?;
+        if let Some(max_pages) = max_pages {
+            request = request.max_pages(max_pages);
+        }
 
-        Ok(result)
+        request.stream(self)
     }
 
     /// Gives detailed information about a Free Company (FC) from a Free Company ID.
@@ -251,40 +394,1140 @@ impl XIVAPIClient {
     ///
     /// # Returns
     ///
-    /// A `Result` containing detailed information about the Free Company as `FreeCompanyResult` or a `reqwest::Error`
+    /// A `Result` containing detailed information about the Free Company as `FreeCompanyResult` or an `Error`
     /// if the request to XIVAPI fails.
+    #[deprecated(note = "use `FreeCompanyLookupRequest`, e.g. `.extended(true).data([\"FCM\"])`")]
     pub async fn free_company_lookup(
         &self,
-        character_id: &str,
+        free_company_id: &str,
+        extended: bool,
+        data: Option<Vec<&str>>,
+    ) -> Result<FreeCompanyResult, Error> {
+        FreeCompanyLookupRequest::new(free_company_id)
+            .extended(extended)
+            .data(data.unwrap_or_default())
+            .send(self)
+            .await
+    }
+
+    /// Same as `free_company_lookup`, but also reports whether the result came from the cache.
+    ///
+    /// With `XIVAPIClientBuilder::stale_if_error()` or `XIVAPIClientBuilder::offline()`, the result may
+    /// be an out of date copy of the Free Company, which is marked as stale.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing detailed information about the Free Company as `Cached<FreeCompanyResult>`
+    /// or an `Error` if the request to XIVAPI fails and no usable cached response exists.
+    #[deprecated(note = "use `FreeCompanyLookupRequest::send_cached()`")]
+    pub async fn free_company_lookup_cached(
+        &self,
+        free_company_id: &str,
         extended: bool,
         data: Option<Vec<&str>>,
-    ) -> Result<FreeCompanyResult, reqwest::Error> {
-        let mut seach_params = Vec::new();
+    ) -> Result<Cached<FreeCompanyResult>, Error> {
+        FreeCompanyLookupRequest::new(free_company_id)
+            .extended(extended)
+            .data(data.unwrap_or_default())
+            .send_cached(self)
+            .await
+    }
+
+    /// Does a name-based search for a linkshell.
+    ///
+    /// Returns basic information of all linkshells that match the search criteria.
+    /// If no linkshells match the criteria, the returned array will be empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the linkshell to search for.
+    /// * `server` - An optional server name filter for the linkshell search.
+    /// * `page` - An optional page number for paginated results.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `LinkshellSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn linkshell_search(
+        &self,
+        name: &str,
+        server: Option<&str>,
+        page: Option<u32>,
+    ) -> Result<LinkshellSearchResults, Error> {
+        let mut request = LinkshellSearchRequest::new(name);
+
+        if let Some(server) = server {
+            request = request.server(server);
+        }
+
+        if let Some(page) = page {
+            request = request.page(page);
+        }
+
+        request.send(self).await
+    }
+
+    /// Gives information about a linkshell and its members from a linkshell ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `linkshell_id` - The ID of the linkshell to look up.
+    /// * `page` - An optional page number for the member list, which has 50 members per page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the linkshell and a page of its members as `LinkshellResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn linkshell_lookup(
+        &self,
+        linkshell_id: &str,
+        page: Option<u32>,
+    ) -> Result<LinkshellResult, Error> {
+        let mut request = LinkshellLookupRequest::new(linkshell_id);
+
+        if let Some(page) = page {
+            request = request.page(page);
+        }
+
+        request.send(self).await
+    }
+
+    /// Does a name-based search for a cross-world linkshell.
+    ///
+    /// Returns basic information of all cross-world linkshells that match the search criteria.
+    /// If no cross-world linkshells match the criteria, the returned array will be empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cross-world linkshell to search for.
+    /// * `data_center` - An optional data center name filter for the cross-world linkshell search.
+    /// * `page` - An optional page number for paginated results.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `LinkshellSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn crossworld_linkshell_search(
+        &self,
+        name: &str,
+        data_center: Option<&str>,
+        page: Option<u32>,
+    ) -> Result<LinkshellSearchResults, Error> {
+        let mut request = LinkshellSearchRequest::new(name).crossworld(true);
+
+        if let Some(data_center) = data_center {
+            request = request.server(data_center);
+        }
+
+        if let Some(page) = page {
+            request = request.page(page);
+        }
+
+        request.send(self).await
+    }
+
+    /// Gives information about a cross-world linkshell and its members from a cross-world linkshell ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `linkshell_id` - The ID of the cross-world linkshell to look up.
+    /// * `page` - An optional page number for the member list, which has 50 members per page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the cross-world linkshell and a page of its members as `LinkshellResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn crossworld_linkshell_lookup(
+        &self,
+        linkshell_id: &str,
+        page: Option<u32>,
+    ) -> Result<LinkshellResult, Error> {
+        let mut request = LinkshellLookupRequest::new(linkshell_id).crossworld(true);
 
-        if extended {
-            seach_params.push("extended=1".to_string())
+        if let Some(page) = page {
+            request = request.page(page);
         }
 
-        if let Some(data) = data {
-            let data = data.join(",");
+        request.send(self).await
+    }
+
+    /// Does a name-based search for a PvP team.
+    ///
+    /// Returns basic information of all PvP teams that match the search criteria.
+    /// If no PvP teams match the criteria, the returned array will be empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the PvP team to search for.
+    /// * `data_center` - An optional data center name filter for the PvP team search.
+    /// * `page` - An optional page number for paginated results.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `PvPTeamSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn pvp_team_search(
+        &self,
+        name: &str,
+        data_center: Option<&str>,
+        page: Option<u32>,
+    ) -> Result<PvPTeamSearchResults, Error> {
+        let mut request = PvPTeamSearchRequest::new(name);
 
-            seach_params.push(format!("data={}", data))
+        if let Some(data_center) = data_center {
+            request = request.server(data_center);
         }
 
-        let search_string = format!(
-            "https://xivapi.com/freecompany/{}?{}",
-            character_id,
-            seach_params.join("&")
-        );
+        if let Some(page) = page {
+            request = request.page(page);
+        }
 
-        let result: FreeCompanyResult = self.client.get(search_string).send().await?.json().await?;
+        request.send(self).await
+    }
 
-        Ok(result)
+    /// Gives information about a PvP team and its members from a PvP team ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pvp_team_id` - The ID of the PvP team to look up, e.g. `Character::pvp_team_id`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the PvP team as `PvPTeamResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn pvp_team_lookup(&self, pvp_team_id: &str) -> Result<PvPTeamResult, Error> {
+        PvPTeamLookupRequest::new(pvp_team_id).send(self).await
     }
 }
 
-impl Default for XIVAPIClient {
-    fn default() -> Self {
-        Self::new()
+impl XIVAPIClient {
+    /// Removes every cached response for an endpoint path, such as `/character/12345678`.
+    ///
+    /// Applies to both the in-memory and the on-disk cache. Does nothing if neither is enabled.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `Error::Cache` if a file of the on-disk cache couldn't be removed.
+    pub async fn invalidate(&self, path: &str) -> Result<(), Error> {
+        let segments: Vec<&str> = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        let url = self.url(&segments).build();
+        let path = url.path();
+
+        if let Some(cache) = &self.cache {
+            cache.invalidate_path(path);
+        }
+
+        if let Some(disk) = &self.disk {
+            disk.invalidate_path(path).await.map_err(Error::Cache)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes every cached lookup of a character.
+    pub async fn invalidate_character(&self, character_id: u32) -> Result<(), Error> {
+        self.invalidate(&format!("/character/{}", character_id))
+            .await
+    }
+
+    /// Removes every cached lookup of a Free Company.
+    pub async fn invalidate_free_company(&self, free_company_id: &str) -> Result<(), Error> {
+        self.invalidate(&format!("/freecompany/{}", free_company_id))
+            .await
+    }
+
+    /// Removes every response from the in-memory and on-disk cache.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `Error::Cache` if a file of the on-disk cache couldn't be removed.
+    pub async fn clear_cache(&self) -> Result<(), Error> {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+
+        if let Some(disk) = &self.disk {
+            disk.clear().await.map_err(Error::Cache)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl XIVAPIClient {
+    /// Starts building the URL for an endpoint, relative to the base URL.
+    pub(crate) fn url(&self, segments: &[&str]) -> RequestUrl {
+        RequestUrl::new(&self.base_url, segments)
+    }
+
+    /// Sends a GET request to XIVAPI and decodes the response.
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        url: Url,
+    ) -> Result<T, Error> {
+        self.get_cached(endpoint, url).await.map(Cached::into_inner)
+    }
+
+    /// Sends a GET request to XIVAPI and decodes the response, going through the caches.
+    ///
+    /// Fresh responses are served from memory first, then from disk. In offline mode, or when
+    /// XIVAPI is unreachable and `stale_if_error` is set, expired responses are served as stale.
+    pub(crate) async fn get_cached<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        url: Url,
+    ) -> Result<Cached<T>, Error> {
+        let cache_key = if self.cache.is_some() || self.disk.is_some() {
+            Some(cache::normalize(&url))
+        } else {
+            None
+        };
+
+        if let Some(key) = &cache_key {
+            if let Some(cached) = self.cache_lookup(endpoint, key).await {
+                let stored_at = cached.stored_at;
+                return Ok(Cached {
+                    data: error::decode(StatusCode::OK, &cached.url, &cached.body)?,
+                    freshness: Freshness::Cached { stored_at },
+                });
+            }
+        }
+
+        if self.offline {
+            return match self.stale_lookup(cache_key.as_deref()).await {
+                Some(cached) => decode_stale(cached),
+                None => Err(Error::Offline {
+                    url: url.to_string(),
+                }),
+            };
+        }
+
+        let response = match self.fetch(&url).await {
+            Ok(response) => response,
+            Err(error) if self.stale_if_error && error.is_unavailable() => {
+                return match self.stale_lookup(cache_key.as_deref()).await {
+                    Some(cached) => decode_stale(cached),
+                    None => Err(error),
+                };
+            }
+            Err(error) => return Err(error),
+        };
+
+        let data = error::decode(response.status, &response.url, &response.body)?;
+
+        if let Some(key) = cache_key {
+            let cached = CachedResponse {
+                url: response.url,
+                body: response.body,
+                stored_at: SystemTime::now(),
+            };
+
+            if let Some(disk) = &self.disk {
+                // Failing to persist a response shouldn't fail the request that fetched it.
+                let _ = disk.insert(&key, &cached).await;
+            }
+
+            if let Some(cache) = &self.cache {
+                cache.insert(key, cached);
+            }
+        }
+
+        Ok(Cached {
+            data,
+            freshness: Freshness::Live,
+        })
+    }
+
+    /// Looks for a response that is still within its TTL, in memory first and then on disk.
+    async fn cache_lookup(&self, endpoint: Endpoint, key: &str) -> Option<CachedResponse> {
+        if let Some(cached) = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(endpoint, key))
+        {
+            return Some(cached);
+        }
+
+        let cached = self.disk.as_ref()?.get(endpoint, key).await?;
+
+        if let Some(cache) = &self.cache {
+            cache.insert(key.to_string(), cached.clone());
+        }
+
+        Some(cached)
+    }
+
+    /// Looks for a response of any age, in memory first and then on disk.
+    async fn stale_lookup(&self, key: Option<&str>) -> Option<CachedResponse> {
+        let key = key?;
+
+        if let Some(cached) = self.cache.as_ref().and_then(|cache| cache.get_stale(key)) {
+            return Some(cached);
+        }
+
+        self.disk.as_ref()?.get_stale(key).await
+    }
+
+    /// Sends a GET request to XIVAPI without going through the caches.
+    pub(crate) async fn fetch_uncached(&self, url: &Url) -> Result<RawResponse, Error> {
+        if self.offline {
+            return Err(Error::Offline {
+                url: url.to_string(),
+            });
+        }
+
+        self.fetch(url).await
+    }
+
+    /// Sends a GET request to XIVAPI and checks the response for errors.
+    ///
+    /// Failed requests are retried according to the client's `RetryPolicy`, if one is configured.
+    async fn fetch(&self, url: &Url) -> Result<RawResponse, Error> {
+        let mut attempt = 1;
+
+        loop {
+            let response = self.send(url).await;
+            let retry_after = response
+                .as_ref()
+                .ok()
+                .and_then(|response| response.retry_after);
+
+            let result = response.and_then(|response| {
+                error::check(response.status, &response.url, &response.body).map(|_| response)
+            });
+
+            let error = match result {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            match &self.retry {
+                Some(retry) if retry.should_retry(&error, attempt) => {
+                    tokio::time::sleep(retry.backoff(attempt, retry_after)).await;
+                    attempt += 1;
+                }
+                _ if attempt > 1 => {
+                    return Err(Error::Retried {
+                        attempts: attempt,
+                        source: Box::new(error),
+                    })
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    /// Sends a single GET request to XIVAPI without decoding the response.
+    ///
+    /// If private keys are configured, one is attached to the request. A rate limited key is
+    /// swapped for the next available one and the request is sent again.
+    ///
+    /// If the rate limiter is enabled, the request waits for its turn before being sent.
+    async fn send(&self, url: &Url) -> Result<RawResponse, Error> {
+        loop {
+            let key = self.keys.as_ref().map(|keys| keys.next());
+            let key_index = key.as_ref().map(|(index, _)| *index);
+
+            if let Some(limiter) = &self.limiter {
+                limiter.acquire(key_index).await;
+            }
+
+            let mut request = self.client.get(url.clone());
+
+            if let Some((_, key)) = &key {
+                request = request.query(&[(keys::PRIVATE_KEY_PARAM, key.expose())]);
+            }
+
+            let response = request.send().await.map_err(keys::redact_error)?;
+            let status = response.status();
+            let retry_after = ratelimit::retry_after(response.headers());
+
+            if let Some(limiter) = &self.limiter {
+                limiter.observe(key_index, status, response.headers());
+            }
+
+            if let (Some(pool), Some(index)) = (&self.keys, key_index) {
+                if status == StatusCode::TOO_MANY_REQUESTS && pool.throttle(index, retry_after) {
+                    continue;
+                }
+            }
+
+            let mut url = response.url().clone();
+            keys::redact(&mut url);
+
+            let body = response.bytes().await.map_err(keys::redact_error)?;
+
+            return Ok(RawResponse {
+                status,
+                url,
+                body,
+                retry_after,
+            });
+        }
+    }
+}
+
+/// Decodes a cached response that is being served past its TTL.
+fn decode_stale<T: DeserializeOwned>(cached: CachedResponse) -> Result<Cached<T>, Error> {
+    Ok(Cached {
+        data: error::decode(StatusCode::OK, &cached.url, &cached.body)?,
+        freshness: Freshness::Stale {
+            stored_at: cached.stored_at,
+        },
+    })
+}
+
+/// An undecoded response from XIVAPI.
+struct RawResponse {
+    status: StatusCode,
+    url: Url,
+    body: Bytes,
+    retry_after: Option<Duration>,
+}
+
+impl Default for XIVAPIClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{
+        character::CharacterData,
+        request::{CharacterSearchRequest, FreeCompanyLookupRequest},
+        CacheConfig, Error, Freshness, RetryPolicy, XIVAPIClient,
+    };
+
+    const CHARACTER: &str = include_str!("../tests/fixtures/character.json");
+
+    const CHARACTER_EXTENDED: &str = include_str!("../tests/fixtures/character_extended.json");
+
+    const CHARACTER_SEARCH: &str = r#"{
+        "Pagination": {
+            "Page": 1, "PageNext": null, "PagePrev": null, "PageTotal": 1,
+            "Results": 1, "ResultsPerPage": 50, "ResultsTotal": 1
+        },
+        "Results": [{
+            "Avatar": "https://img2.finalfantasyxiv.com/f/avatar.jpg",
+            "FeastMatches": 0, "ID": 12345678, "Lang": "en",
+            "Name": "Tami Pesagniyah", "Rank": null, "RankIcon": null,
+            "Server": "Omega (Chaos)"
+        }]
+    }"#;
+
+    const FREE_COMPANY: &str = include_str!("../tests/fixtures/free_company.json");
+
+    const FREE_COMPANY_SEARCH: &str = r#"{
+        "Pagination": {
+            "Page": 1, "PageNext": null, "PagePrev": null, "PageTotal": 1,
+            "Results": 1, "ResultsPerPage": 50, "ResultsTotal": 1
+        },
+        "Results": [{
+            "Crest": [], "ID": "9234349560946589999", "Name": "SEES", "Server": "Omega (Chaos)"
+        }]
+    }"#;
+
+    /// Mounts the list of worlds that searches filtered by server are validated against.
+    async fn mount_servers(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/servers/dc"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"Chaos": ["Omega", "Ragnarok"], "Light": ["Phoenix"]}"#),
+            )
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_character_search() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .and(query_param("name", "Tami Pesagniyah"))
+            .and(query_param("server", "Omega"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await?;
+
+        assert_eq!(result.results[0].name, "Tami Pesagniyah");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_character_lookup() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await?;
+
+        let result = client
+            .character_lookup(result.results[0].id, false, CharacterData::empty())
+            .await?;
+
+        assert_eq!(result.character.name, "Tami Pesagniyah");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_free_company_search() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/freecompany/search"))
+            .and(query_param("name", "SEES"))
+            .and(query_param("server", "Omega"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FREE_COMPANY_SEARCH))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .free_company_search("SEES", Some("Omega"), None)
+            .await?;
+
+        assert_eq!(result.results[0].id, "9234349560946589999");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_free_company_lookup() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/freecompany/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FREE_COMPANY_SEARCH))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/freecompany/9234349560946589999"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FREE_COMPANY))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .free_company_search("SEES", Some("Omega"), None)
+            .await?;
+
+        let result = FreeCompanyLookupRequest::new(&result.results[0].id)
+            .send(&client)
+            .await?;
+
+        assert_eq!(result.free_company.tag, "SEES");
+        assert!(result.free_company_members.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_character_search_base_url() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .and(query_param("server", "Omega"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await?;
+
+        assert_eq!(result.results[0].id, 12345678);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_character_lookup_not_found() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/1"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(
+                r#"{"Error":true,"Subject":"XIVAPI Service Error","Message":"Character not found","Note":null}"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let error = client
+            .character_lookup(1, false, CharacterData::empty())
+            .await
+            .unwrap_err();
+
+        assert!(error.is_not_found());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_private_key_rotation() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(query_param("private_key", "first"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(query_param("private_key", "second"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .private_keys(["first", "second"])
+            .build()?;
+
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+
+        assert!(!format!("{:?}", client).contains("first"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_errors() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .retry(RetryPolicy::new().initial_backoff(Duration::from_millis(1)))
+            .build()?;
+
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_reports_attempts() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .retry(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .initial_backoff(Duration::from_millis(1)),
+            )
+            .build()?;
+
+        let error = client
+            .character_search("Tami Pesagniyah", None, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.attempts(), 3);
+        assert!(error.is_server_error());
+
+        Ok(())
+    }
+
+    fn search_page(page: u32, total: u32) -> String {
+        let next = if page < total {
+            (page + 1).to_string()
+        } else {
+            "null".to_string()
+        };
+
+        format!(
+            r#"{{
+                "Pagination": {{
+                    "Page": {page}, "PageNext": {next}, "PagePrev": null, "PageTotal": {total},
+                    "Results": 1, "ResultsPerPage": 1, "ResultsTotal": {total}
+                }},
+                "Results": [{{
+                    "Avatar": "", "FeastMatches": 0, "ID": {page}, "Lang": "en",
+                    "Name": "Tami Pesagniyah", "Rank": null, "RankIcon": null,
+                    "Server": "Omega (Chaos)"
+                }}]
+            }}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_character_search_all() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        for page in 1..=3 {
+            Mock::given(method("GET"))
+                .and(path("/character/search"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_string(search_page(page, 3)))
+                .mount(&server)
+                .await;
+        }
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let ids: Vec<u32> = client
+            .character_search_all("Tami Pesagniyah", None, None)
+            .map(|result| result.map(|character| character.id))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(ids, [1, 2, 3]);
+
+        let capped = client
+            .character_search_all("Tami Pesagniyah", None, Some(2))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(capped.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_validates_server() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .and(query_param("server", "_dc_Chaos"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let error = client
+            .character_search("Tami Pesagniyah", Some("Omegaa"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnknownServer(_)));
+
+        CharacterSearchRequest::new("Tami Pesagniyah")
+            .data_center("Chaos")
+            .send(&client)
+            .await?;
+
+        let worlds = client.servers().await?;
+        assert_eq!(worlds.len(), 3);
+        assert_eq!(worlds[2].data_center, "Light");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_server_catalog_failure() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/servers/dc"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let error = client
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await
+            .unwrap_err();
+        assert!(error.is_server_error());
+
+        let error = client
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::ServerCatalogUnavailable(_)));
+
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+
+        let unchecked = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .validate_servers(false)
+            .build()?;
+        unchecked
+            .character_search("Tami Pesagniyah", Some("Omega"), None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cached_lookup() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_SEARCH))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .cache(CacheConfig::new())
+            .build()?;
+
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+
+        client.invalidate("/character/search").await?;
+        client
+            .character_search("Tami Pesagniyah", None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_character_lookup_extended() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .and(query_param("extended", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER_EXTENDED))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+        let result = client
+            .character_lookup_extended(12345678, CharacterData::empty())
+            .await?;
+
+        assert_eq!(result.character.town.name, "Gridania");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_character_lookup_missing_sections() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .and(query_param("data", "FR,PVP"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+        let error = client
+            .character_lookup(
+                12345678,
+                false,
+                CharacterData::FRIENDS | CharacterData::PVP_TEAM,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::MissingSections(CharacterData::FRIENDS)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_stale_and_offline() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        let directory =
+            std::env::temp_dir().join(format!("xivapi-rust-stale-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CHARACTER))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let config = CacheConfig::new().character_ttl(Duration::ZERO);
+
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .disk_cache(&directory)
+            .cache(config.clone())
+            .build()?;
+
+        let live = client
+            .character_lookup_cached(12345678, false, CharacterData::empty())
+            .await?;
+        assert_eq!(live.freshness, Freshness::Live);
+
+        // A new client shares nothing but the directory, like a restarted program.
+        let client = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .disk_cache(&directory)
+            .cache(config.clone())
+            .stale_if_error(true)
+            .build()?;
+
+        let stale = client
+            .character_lookup_cached(12345678, false, CharacterData::empty())
+            .await?;
+        assert!(stale.is_stale());
+        assert_eq!(stale.data.character.name, "Tami Pesagniyah");
+
+        let offline = XIVAPIClient::builder()
+            .base_url(server.uri())
+            .disk_cache(&directory)
+            .offline(true)
+            .build()?;
+
+        assert!(offline
+            .character_lookup(12345678, false, CharacterData::empty())
+            .await
+            .is_ok());
+        assert!(matches!(
+            offline
+                .character_lookup(1, false, CharacterData::empty())
+                .await,
+            Err(Error::Offline { .. })
+        ));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_free_company_search_encodes_name() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        mount_servers(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/freecompany/search"))
+            .and(query_param("name", "Tea & Biscuits"))
+            .and(query_param("server", "Phoenix"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{
+                    "Pagination": {
+                        "Page": 1, "PageNext": null, "PagePrev": null, "PageTotal": 1,
+                        "Results": 1, "ResultsPerPage": 50, "ResultsTotal": 1
+                    },
+                    "Results": [{
+                        "Crest": [], "ID": "9228438586435651234",
+                        "Name": "Tea & Biscuits", "Server": "Phoenix (Light)"
+                    }]
+                }"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let result = client
+            .free_company_search("Tea & Biscuits", Some("Phoenix"), None)
+            .await?;
+
+        assert_eq!(result.results[0].name, "Tea & Biscuits");
+
+        Ok(())
     }
 }
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{character::CharacterSearch, pagination::Pagination};
+
+/// Collection of linkshells or cross-world linkshells that match a name search.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct LinkshellSearchResults {
+    /// Pagination information for the search results.
+    pub pagination: Pagination,
+    /// List of linkshells that match the search criteria.
+    pub results: Vec<LinkshellSearch>,
+}
+
+/// Basic information about a linkshell obtained from a name search.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct LinkshellSearch {
+    /// The ID of the linkshell.
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// The name of the linkshell.
+    pub name: String,
+    /// The server of a linkshell, or the data center of a cross-world linkshell.
+    #[serde(alias = "DataCenter")]
+    pub server: String,
+}
+
+/// All information obtained from a linkshell ID request.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct LinkshellResult {
+    /// Detailed information about the linkshell.
+    pub linkshell: Linkshell,
+}
+
+/// A linkshell or cross-world linkshell, along with a page of its members.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Linkshell {
+    /// The ID of the linkshell.
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// Pagination information for the member list.
+    pub pagination: Pagination,
+    /// The name and location of the linkshell.
+    pub profile: LinkshellProfile,
+    /// The members on the requested page, with their rank in the linkshell if they have one.
+    pub results: Vec<CharacterSearch>,
+}
+
+/// The name and location of a linkshell.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct LinkshellProfile {
+    /// The name of the linkshell.
+    pub name: String,
+    /// The server of a linkshell, or the data center of a cross-world linkshell, if available.
+    #[serde(alias = "DataCenter")]
+    pub server: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkshellResult;
+
+    #[test]
+    fn test_crossworld_linkshell() {
+        let result: LinkshellResult = serde_json::from_str(
+            r#"{
+                "Linkshell": {
+                    "ID": "f1b0b5c6d4b5d1e2a8f1c1e6f4b6c7e4a8b5c9d1",
+                    "Pagination": {
+                        "Page": 1, "PageNext": null, "PagePrev": null, "PageTotal": 1,
+                        "Results": 1, "ResultsPerPage": 50, "ResultsTotal": 1
+                    },
+                    "Profile": {"Name": "Static Night", "DataCenter": "Chaos"},
+                    "Results": [{
+                        "Avatar": "https://img2.finalfantasyxiv.com/f/avatar.jpg",
+                        "FeastMatches": 0, "ID": 12345678, "Lang": "en",
+                        "Name": "Tami Pesagniyah", "Rank": "Master",
+                        "RankIcon": "https://img.finalfantasyxiv.com/lds/h/master.png",
+                        "Server": "Omega"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.linkshell.profile.server.as_deref(), Some("Chaos"));
+        assert_eq!(result.linkshell.results[0].rank.as_deref(), Some("Master"));
+    }
+}
@@ -0,0 +1,294 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{request::NewsRequest, Error, XIVAPIClient};
+
+/// The Lodestone news feeds mirrored by XIVAPI.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NewsCategory {
+    /// General news and announcements.
+    Topics,
+    /// Notices, such as known issues and follow-ups.
+    Notices,
+    /// Scheduled maintenance.
+    Maintenance,
+    /// Patch notes and other updates.
+    Updates,
+    /// The status of services, such as outages and emergency maintenance.
+    Status,
+    /// Posts by the development team on the official forums.
+    DevPosts,
+}
+
+impl NewsCategory {
+    pub(crate) fn path(self) -> &'static str {
+        match self {
+            NewsCategory::Topics => "news",
+            NewsCategory::Notices => "notices",
+            NewsCategory::Maintenance => "maintenance",
+            NewsCategory::Updates => "updates",
+            NewsCategory::Status => "status",
+            NewsCategory::DevPosts => "devposts",
+        }
+    }
+}
+
+/// An entry of a Lodestone news feed.
+#[derive(Serialize, Clone, Debug)]
+pub struct NewsItem {
+    /// The title of the entry.
+    pub title: String,
+    /// The URL of the entry on the Lodestone.
+    pub url: String,
+    /// When the entry was published.
+    pub time: DateTime<Utc>,
+    /// The URL of the entry's banner image, if it has one.
+    pub banner: Option<String>,
+    /// The tag in front of the title, e.g. `[Maintenance]` or `[Follow-up]`, if it has one.
+    pub tag: Option<String>,
+    /// The feed the entry came from.
+    pub category: NewsCategory,
+}
+
+/// A scheduled maintenance, with the start and end times XIVAPI lists for it.
+#[derive(Serialize, Clone, Debug)]
+pub struct MaintenanceWindow {
+    /// The announcement of the maintenance.
+    pub item: NewsItem,
+    /// When the maintenance starts, if XIVAPI lists it in a format that can be read.
+    pub start: Option<DateTime<Utc>>,
+    /// When the maintenance is scheduled to end, if XIVAPI lists it in a format that can be read.
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl MaintenanceWindow {
+    /// Returns true if the maintenance is in progress at the given time.
+    pub fn is_active_at(&self, time: DateTime<Utc>) -> bool {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => start <= time && time < end,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the maintenance is in progress right now.
+    pub fn is_active(&self) -> bool {
+        self.is_active_at(Utc::now())
+    }
+}
+
+/// A feed entry as XIVAPI returns it.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct RawNewsItem {
+    title: String,
+    url: String,
+    #[serde(deserialize_with = "timestamp")]
+    time: DateTime<Utc>,
+    #[serde(default)]
+    banner: Option<String>,
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default, deserialize_with = "optional_timestamp")]
+    start: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "optional_timestamp")]
+    end: Option<DateTime<Utc>>,
+}
+
+impl RawNewsItem {
+    pub(crate) fn into_item(self, category: NewsCategory) -> NewsItem {
+        NewsItem {
+            title: self.title,
+            url: self.url,
+            time: self.time,
+            banner: self.banner.filter(|banner| !banner.is_empty()),
+            tag: self.tag.filter(|tag| !tag.is_empty()),
+            category,
+        }
+    }
+
+    pub(crate) fn into_window(self, category: NewsCategory) -> MaintenanceWindow {
+        MaintenanceWindow {
+            start: self.start,
+            end: self.end,
+            item: self.into_item(category),
+        }
+    }
+}
+
+/// A timestamp given as Unix seconds or as an RFC 3339 string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Timestamp {
+    Unix(i64),
+    Text(String),
+}
+
+/// A timestamp that may also be missing or in an unexpected format.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LenientTimestamp {
+    Valid(Timestamp),
+    Other(serde::de::IgnoredAny),
+}
+
+impl Timestamp {
+    fn parse<E: serde::de::Error>(self) -> Result<DateTime<Utc>, E> {
+        let time = match &self {
+            Timestamp::Unix(seconds) => Utc.timestamp_opt(*seconds, 0).single(),
+            Timestamp::Text(text) => DateTime::parse_from_rfc3339(text)
+                .ok()
+                .map(|time| time.with_timezone(&Utc)),
+        };
+
+        time.ok_or_else(|| match self {
+            Timestamp::Unix(seconds) => E::custom(format!("invalid timestamp {seconds}")),
+            Timestamp::Text(text) => E::custom(format!("invalid timestamp {text:?}")),
+        })
+    }
+}
+
+fn timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    Timestamp::deserialize(deserializer)?.parse()
+}
+
+/// Reads the start or end of a maintenance, where a time that can't be read is treated as not
+/// listed, so one malformed entry doesn't fail the whole feed.
+fn optional_timestamp<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error> {
+    Ok(match LenientTimestamp::deserialize(deserializer)? {
+        LenientTimestamp::Valid(time) => time.parse::<D::Error>().ok(),
+        LenientTimestamp::Other(_) => None,
+    })
+}
+
+impl XIVAPIClient {
+    /// Gets the latest entries of a Lodestone news feed.
+    ///
+    /// Use `NewsRequest` to get the entries in another language than the client's.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The feed to get, e.g. `NewsCategory::Topics`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the entries as `Vec<NewsItem>`, newest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn lodestone_news(&self, category: NewsCategory) -> Result<Vec<NewsItem>, Error> {
+        NewsRequest::new(category).send(self).await
+    }
+
+    /// Gets the latest scheduled maintenances.
+    ///
+    /// Use `NewsRequest::send_maintenance()` to get them in another language than the client's.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the maintenances as `Vec<MaintenanceWindow>`, newest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn lodestone_maintenance(&self) -> Result<Vec<MaintenanceWindow>, Error> {
+        NewsRequest::new(NewsCategory::Maintenance)
+            .send_maintenance(self)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::NewsCategory;
+    use crate::{Error, XIVAPIClient};
+
+    #[tokio::test]
+    async fn test_news_and_maintenance() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/lodestone/news"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{
+                    "Banner": "https://img.finalfantasyxiv.com/t/banner.png",
+                    "Html": "<p>Hello</p>",
+                    "Time": 1697500800,
+                    "Title": "Patch 6.5 Notes",
+                    "Url": "https://na.finalfantasyxiv.com/lodestone/topics/detail/1"
+                }]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/lodestone/maintenance"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{
+                    "Tag": "[Maintenance]",
+                    "Time": "2023-10-16T08:00:00Z",
+                    "Title": "All Worlds Maintenance (Oct. 17)",
+                    "Url": "https://na.finalfantasyxiv.com/lodestone/news/detail/2",
+                    "Start": "2023-10-17T08:00:00Z",
+                    "End": 1697558400
+                }]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let news = client.lodestone_news(NewsCategory::Topics).await?;
+        assert_eq!(news[0].category, NewsCategory::Topics);
+        assert_eq!(news[0].time, Utc.timestamp_opt(1697500800, 0).unwrap());
+
+        let maintenance = client.lodestone_maintenance().await?;
+        let window = &maintenance[0];
+        assert_eq!(window.item.tag.as_deref(), Some("[Maintenance]"));
+        assert!(window.is_active_at(Utc.with_ymd_and_hms(2023, 10, 17, 12, 0, 0).unwrap()));
+        assert!(!window.is_active_at(Utc.with_ymd_and_hms(2023, 10, 17, 16, 0, 0).unwrap()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_timestamps() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/lodestone/maintenance"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{
+                    "Time": 1697414400,
+                    "Title": "All Worlds Maintenance (Oct. 17)",
+                    "Url": "https://na.finalfantasyxiv.com/lodestone/news/detail/2",
+                    "Start": "Oct. 17, 2023 1:00 a.m.",
+                    "End": 99999999999999
+                }, {
+                    "Time": 1696809600,
+                    "Title": "All Worlds Maintenance (Oct. 10)",
+                    "Url": "https://na.finalfantasyxiv.com/lodestone/news/detail/1",
+                    "Start": 1696953600,
+                    "End": null
+                }]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let maintenance = client.lodestone_maintenance().await?;
+        assert_eq!(maintenance.len(), 2);
+        assert_eq!(maintenance[0].start, None);
+        assert_eq!(maintenance[0].end, None);
+        assert_eq!(
+            maintenance[1].start,
+            Some(Utc.timestamp_opt(1696953600, 0).unwrap())
+        );
+        assert_eq!(maintenance[1].end, None);
+
+        Ok(())
+    }
+}
@@ -1,13 +1,249 @@
+use std::{future::Future, ops::RangeInclusive};
+
+use futures::stream::{self, BoxStream, StreamExt};
 use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use crate::{
+    character::{CharacterSearch, CharacterSearchResults},
+    freecompany::{FreeCompanySearch, FreeCompanySearchResults},
+    linkshell::{LinkshellSearch, LinkshellSearchResults},
+    pvpteam::{PvPTeamSearch, PvPTeamSearchResults},
+    Error, XIVAPIClient,
+};
 
-#[derive(Deserialize, Serialize, Debug)]
+/// Pagination information returned with search results.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Pagination {
-    pub page: u8,
-    pub page_next: Option<u8>,
-    pub page_prev: Option<u8>,
-    pub page_total: u8,
-    pub results: u8,
-    pub results_per_page: u8,
-    pub results_total: u16,
+    /// The current page number, starting from 1.
+    pub page: u32,
+    /// The next page number, if there is one.
+    pub page_next: Option<u32>,
+    /// The previous page number, if there is one.
+    pub page_prev: Option<u32>,
+    /// The total number of pages.
+    pub page_total: u32,
+    /// The number of results on the current page.
+    pub results: u32,
+    /// The maximum number of results on each page.
+    pub results_per_page: u32,
+    /// The total number of results across every page.
+    pub results_total: u32,
+}
+
+impl Pagination {
+    /// Returns true if there are more pages after the current one.
+    pub fn has_next(&self) -> bool {
+        self.page_next.is_some()
+    }
+
+    /// Returns the number of results on the pages after the current one.
+    pub fn remaining(&self) -> u32 {
+        let seen = self
+            .page
+            .saturating_sub(1)
+            .saturating_mul(self.results_per_page)
+            .saturating_add(self.results);
+
+        self.results_total.saturating_sub(seen)
+    }
+
+    /// Returns an iterator over every page number of the search, which can be passed to the
+    /// `page` argument of the search methods.
+    pub fn pages(&self) -> RangeInclusive<u32> {
+        1..=self.page_total
+    }
+}
+
+/// A page of search results that can be followed to the next page.
+pub(crate) trait SearchPage {
+    type Item;
+
+    fn into_parts(self) -> (Pagination, Vec<Self::Item>);
+}
+
+impl SearchPage for CharacterSearchResults {
+    type Item = CharacterSearch;
+
+    fn into_parts(self) -> (Pagination, Vec<CharacterSearch>) {
+        (self.pagination, self.results)
+    }
+}
+
+impl SearchPage for FreeCompanySearchResults {
+    type Item = FreeCompanySearch;
+
+    fn into_parts(self) -> (Pagination, Vec<FreeCompanySearch>) {
+        (self.pagination, self.results)
+    }
+}
+
+impl SearchPage for LinkshellSearchResults {
+    type Item = LinkshellSearch;
+
+    fn into_parts(self) -> (Pagination, Vec<LinkshellSearch>) {
+        (self.pagination, self.results)
+    }
+}
+
+impl SearchPage for PvPTeamSearchResults {
+    type Item = PvPTeamSearch;
+
+    fn into_parts(self) -> (Pagination, Vec<PvPTeamSearch>) {
+        (self.pagination, self.results)
+    }
+}
+
+/// A search request that can be sent for any page of its results.
+pub(crate) trait Paged: Clone + Send + Sync + 'static {
+    type Page: SearchPage + Send + 'static;
+
+    /// Returns the page to start from, the most pages to request and whether to prefetch.
+    fn pages(&self) -> (u32, Option<u32>, bool);
+
+    /// Returns a copy of the request for the given page.
+    fn with_page(self, page: u32) -> Self;
+
+    /// Sends the request for a single page.
+    fn send_page(
+        self,
+        client: XIVAPIClient,
+    ) -> impl Future<Output = Result<Self::Page, Error>> + Send;
+}
+
+/// Streams the results of every page of a search request, as configured by the request.
+pub(crate) fn stream_pages<R: Paged>(
+    request: &R,
+    client: &XIVAPIClient,
+) -> BoxStream<'static, Result<<R::Page as SearchPage>::Item, Error>>
+where
+    <R::Page as SearchPage>::Item: Send,
+{
+    let (first_page, max_pages, prefetch) = request.pages();
+    let request = request.clone();
+    let client = client.clone();
+
+    paginate(first_page, max_pages, prefetch, move |page| {
+        request.clone().with_page(page).send_page(client.clone())
+    })
+}
+
+/// A page that is still to be requested, or is already being requested in the background.
+enum NextPage<P> {
+    Page(u32),
+    Prefetch(Prefetch<P>),
+}
+
+/// A page requested ahead of time, which is cancelled if the stream is dropped first.
+struct Prefetch<P>(JoinHandle<Result<P, Error>>);
+
+impl<P> Drop for Prefetch<P> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+struct State<P: SearchPage, F> {
+    fetch: F,
+    items: std::vec::IntoIter<P::Item>,
+    next: Option<NextPage<P>>,
+    fetched: u32,
+    max_pages: Option<u32>,
+    prefetch: bool,
+}
+
+/// Streams the results of every page of a search, starting from `first_page`.
+///
+/// `fetch` requests a single page. At most `max_pages` pages are requested. With `prefetch`,
+/// the next page is requested in the background while the results of the current page are
+/// being consumed, so at most one extra request is in flight at a time.
+pub(crate) fn paginate<P, F, Fut>(
+    first_page: u32,
+    max_pages: Option<u32>,
+    prefetch: bool,
+    fetch: F,
+) -> BoxStream<'static, Result<P::Item, Error>>
+where
+    P: SearchPage + Send + 'static,
+    P::Item: Send,
+    F: Fn(u32) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<P, Error>> + Send + 'static,
+{
+    let state = State::<P, F> {
+        fetch,
+        items: Vec::new().into_iter(),
+        next: (max_pages != Some(0)).then_some(NextPage::Page(first_page)),
+        fetched: 0,
+        max_pages,
+        prefetch,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.items.next() {
+                return Some((Ok(item), state));
+            }
+
+            let page = match state.next.take()? {
+                NextPage::Page(page) => (state.fetch)(page).await,
+                NextPage::Prefetch(mut prefetch) => match (&mut prefetch.0).await {
+                    Ok(result) => result,
+                    Err(error) => std::panic::resume_unwind(error.into_panic()),
+                },
+            };
+
+            // An error ends the stream, since the following page number is unknown.
+            let (pagination, items) = match page {
+                Ok(page) => page.into_parts(),
+                Err(error) => return Some((Err(error), state)),
+            };
+
+            state.fetched += 1;
+            state.items = items.into_iter();
+
+            let more_allowed = state.max_pages.is_none_or(|max| state.fetched < max);
+
+            state.next = match pagination.page_next {
+                Some(page) if more_allowed && page > pagination.page => Some(if state.prefetch {
+                    NextPage::Prefetch(Prefetch(tokio::spawn((state.fetch)(page))))
+                } else {
+                    NextPage::Page(page)
+                }),
+                _ => None,
+            };
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pagination;
+
+    #[test]
+    fn test_helpers() {
+        let pagination: Pagination = serde_json::from_str(
+            r#"{
+                "Page": 2, "PageNext": 3, "PagePrev": 1, "PageTotal": 1700,
+                "Results": 50, "ResultsPerPage": 50, "ResultsTotal": 84990
+            }"#,
+        )
+        .unwrap();
+
+        assert!(pagination.has_next());
+        assert_eq!(pagination.remaining(), 84890);
+        assert_eq!(pagination.pages().count(), 1700);
+
+        let last = Pagination {
+            page: 1700,
+            page_next: None,
+            page_prev: Some(1699),
+            results: 40,
+            ..pagination
+        };
+
+        assert!(!last.has_next());
+        assert_eq!(last.remaining(), 0);
+    }
 }
@@ -0,0 +1,272 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{request::PatchListRequest, serde_helpers, Error, LocalizedNames, XIVAPIClient};
+
+/// An expansion of the game.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Expansion {
+    /// A Realm Reborn, the base game.
+    ARealmReborn,
+    /// Heavensward.
+    Heavensward,
+    /// Stormblood.
+    Stormblood,
+    /// Shadowbringers.
+    Shadowbringers,
+    /// Endwalker.
+    Endwalker,
+    /// Dawntrail.
+    Dawntrail,
+}
+
+impl Expansion {
+    /// Converts the `ExVersion` XIVAPI uses for expansions, starting at 0 for A Realm Reborn.
+    pub fn from_ex_version(ex_version: u8) -> Option<Expansion> {
+        let expansion = match ex_version {
+            0 => Expansion::ARealmReborn,
+            1 => Expansion::Heavensward,
+            2 => Expansion::Stormblood,
+            3 => Expansion::Shadowbringers,
+            4 => Expansion::Endwalker,
+            5 => Expansion::Dawntrail,
+            _ => return None,
+        };
+
+        Some(expansion)
+    }
+}
+
+/// A game patch, as listed by XIVAPI's `/patchlist`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Patch {
+    /// The ID sheet rows use to refer to the patch, e.g. in their `Patch` field.
+    pub id: u32,
+    /// The version of the patch, e.g. `6.5`.
+    pub version: String,
+    /// The name of the patch, e.g. `Growing Light`.
+    pub name: String,
+    /// The name of the patch in every language XIVAPI included.
+    pub names: LocalizedNames,
+    /// The expansion the patch belongs to, if it is known.
+    pub expansion: Option<Expansion>,
+    /// True if the patch released an expansion.
+    pub is_expansion: bool,
+    /// When the patch was released.
+    pub release_date: DateTime<Utc>,
+    /// The URL of the patch's banner image, if it has one.
+    pub banner: Option<String>,
+}
+
+/// A patch as XIVAPI returns it.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct RawPatch {
+    #[serde(rename = "ID")]
+    id: u32,
+    version: String,
+    #[serde(default)]
+    name: String,
+    #[serde(flatten)]
+    names: LocalizedNames,
+    ex_version: u8,
+    #[serde(default, deserialize_with = "serde_helpers::flag")]
+    is_expansion: bool,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    release_date: DateTime<Utc>,
+    #[serde(default)]
+    banner: Option<String>,
+}
+
+impl From<RawPatch> for Patch {
+    fn from(raw: RawPatch) -> Self {
+        Patch {
+            id: raw.id,
+            version: raw.version,
+            name: raw.name,
+            names: raw.names,
+            expansion: Expansion::from_ex_version(raw.ex_version),
+            is_expansion: raw.is_expansion,
+            release_date: raw.release_date,
+            banner: raw.banner.filter(|banner| !banner.is_empty()),
+        }
+    }
+}
+
+/// How long the patch list kept by the client is used before it may be requested again.
+const CATALOG_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The patch list `XIVAPIClient::patch()` and `current_patch()` read from.
+#[derive(Debug, Default)]
+pub(crate) struct PatchCatalog {
+    state: Mutex<Option<LoadedPatches>>,
+}
+
+#[derive(Debug)]
+struct LoadedPatches {
+    patches: Vec<Patch>,
+    fetched_at: Instant,
+}
+
+/// Converts the patches XIVAPI returns, oldest first.
+pub(crate) fn sorted(patches: Vec<RawPatch>) -> Vec<Patch> {
+    let mut patches: Vec<Patch> = patches.into_iter().map(Patch::from).collect();
+    patches.sort_by_key(|patch| (patch.release_date, patch.id));
+    patches
+}
+
+/// Finds the patch with the given ID.
+///
+/// Useful to resolve the `Patch` field of many sheet rows against a single `patch_list()`.
+pub fn find(patches: &[Patch], id: u32) -> Option<&Patch> {
+    patches.iter().find(|patch| patch.id == id)
+}
+
+impl XIVAPIClient {
+    /// Lists every game patch.
+    ///
+    /// Use `PatchListRequest` to get the names in another language than the client's.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the patches as `Vec<Patch>`, oldest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn patch_list(&self) -> Result<Vec<Patch>, Error> {
+        PatchListRequest::new().send(self).await
+    }
+
+    /// Resolves the `Patch` ID of a sheet row, e.g. an item or a quest.
+    ///
+    /// The patch list is kept by the client, so resolving many IDs only requests it once. It is
+    /// only requested again for an ID newer than every known patch, at most once an hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the patch.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Patch`, `None` if there is no patch with that ID, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn patch(&self, id: u32) -> Result<Option<Patch>, Error> {
+        self.with_patches(
+            |patches| patches.iter().all(|patch| patch.id < id),
+            |patches| find(patches, id).cloned(),
+        )
+        .await
+    }
+
+    /// Gets the latest released patch.
+    ///
+    /// Reads the patch list kept by the client, which is requested again at most once an hour.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Patch`, `None` if no patch has been released yet, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn current_patch(&self) -> Result<Option<Patch>, Error> {
+        let now = Utc::now();
+
+        self.with_patches(
+            |_| true,
+            |patches| {
+                patches
+                    .iter()
+                    .rfind(|patch| patch.release_date <= now)
+                    .cloned()
+            },
+        )
+        .await
+    }
+
+    /// Reads the patch list kept by the client.
+    ///
+    /// The list is requested if there is none yet, or if `outdated` says it may be missing
+    /// something and it is older than `CATALOG_TTL`. The lock isn't held during the request.
+    async fn with_patches<R>(
+        &self,
+        outdated: impl Fn(&[Patch]) -> bool,
+        read: impl Fn(&[Patch]) -> R,
+    ) -> Result<R, Error> {
+        if let Some(loaded) = &*self.patches.state.lock().unwrap() {
+            if loaded.fetched_at.elapsed() < CATALOG_TTL || !outdated(&loaded.patches) {
+                return Ok(read(&loaded.patches));
+            }
+        }
+
+        let patches = self.patch_list().await?;
+        let result = read(&patches);
+
+        *self.patches.state.lock().unwrap() = Some(LoadedPatches {
+            patches,
+            fetched_at: Instant::now(),
+        });
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{find, Expansion};
+    use crate::{Error, Language, XIVAPIClient};
+
+    #[tokio::test]
+    async fn test_patch_list() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/patchlist"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{
+                    "Banner": "https://xivapi.com/img-misc/chat_messengericon_goldsaucer.png",
+                    "ExName": "Endwalker", "ExVersion": 4, "ID": 88, "IsExpansion": 0,
+                    "Name": "Growing Light", "Name_en": "Growing Light", "Name_ja": "光の果てへ",
+                    "ReleaseDate": 1696928400, "Version": "6.5"
+                }, {
+                    "Banner": "", "ExVersion": 5, "ID": 97, "IsExpansion": true,
+                    "Name": "Dawntrail", "ReleaseDate": 4102444800, "Version": "7.0"
+                }, {
+                    "Banner": null, "ExVersion": 0, "ID": 1, "IsExpansion": 1,
+                    "Name": "A Realm Reborn", "ReleaseDate": 1377561600, "Version": "2.0"
+                }]"#,
+            ))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        let patches = client.patch_list().await?;
+        assert_eq!(patches[0].version, "2.0");
+        assert!(patches[0].is_expansion);
+        assert_eq!(patches[0].banner, None);
+
+        let patch = find(&patches, 88).unwrap();
+        assert_eq!(patch.expansion, Some(Expansion::Endwalker));
+        assert_eq!(patch.names.get(Language::Japanese), Some("光の果てへ"));
+        assert_eq!(
+            patch.release_date,
+            Utc.timestamp_opt(1696928400, 0).unwrap()
+        );
+
+        assert_eq!(client.patch(97).await?.unwrap().version, "7.0");
+        assert_eq!(client.patch(88).await?.unwrap().name, "Growing Light");
+        assert_eq!(client.patch(1000).await?, None);
+        assert_eq!(client.patch(0).await?, None);
+        assert_eq!(client.current_patch().await?.unwrap().id, 88);
+
+        Ok(())
+    }
+}
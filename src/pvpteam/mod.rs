@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{character::CharacterSearch, pagination::Pagination};
+
+/// Collection of PvP teams that match a name search.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct PvPTeamSearchResults {
+    /// Pagination information for the search results.
+    pub pagination: Pagination,
+    /// List of PvP teams that match the search criteria.
+    pub results: Vec<PvPTeamSearch>,
+}
+
+/// Basic information about a PvP team obtained from a name search.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct PvPTeamSearch {
+    /// List of the images used to make up the team's crest.
+    pub crest: Vec<String>,
+    /// The ID of the PvP team.
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// The name of the PvP team.
+    pub name: String,
+    /// The data center the PvP team belongs to.
+    #[serde(alias = "DataCenter")]
+    pub server: String,
+}
+
+/// All information obtained from a PvP team ID request.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PvPTeamResult {
+    /// Detailed information about the PvP team.
+    #[serde(rename = "PvPTeam")]
+    pub pvp_team: PvPTeam,
+}
+
+/// A PvP team, along with its members.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct PvPTeam {
+    /// The ID of the PvP team.
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// Pagination information for the member list, if available.
+    pub pagination: Option<Pagination>,
+    /// The name, crest and location of the PvP team.
+    pub profile: PvPTeamProfile,
+    /// The members of the PvP team.
+    pub results: Vec<CharacterSearch>,
+}
+
+/// The name, crest and location of a PvP team.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct PvPTeamProfile {
+    /// List of the images used to make up the team's crest.
+    pub crest: Vec<String>,
+    /// The name of the PvP team.
+    pub name: String,
+    /// The server the PvP team was formed on, if available.
+    #[serde(default)]
+    pub server: Option<String>,
+    /// The data center the PvP team belongs to, if available.
+    #[serde(default, rename = "DC", alias = "DataCenter")]
+    pub data_center: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PvPTeamResult;
+
+    #[test]
+    fn test_pvp_team() {
+        let result: PvPTeamResult = serde_json::from_str(
+            r#"{
+                "PvPTeam": {
+                    "ID": "8c3ad7d3d8f6a4c55b5a2a3a2a4c7e1b2f7d3a8e",
+                    "Pagination": null,
+                    "Profile": {
+                        "Crest": ["https://img.finalfantasyxiv.com/c/crest.png"],
+                        "Name": "Feast Mode",
+                        "Server": "Omega",
+                        "DataCenter": "Chaos"
+                    },
+                    "Results": [{
+                        "Avatar": "https://img2.finalfantasyxiv.com/f/avatar.jpg",
+                        "FeastMatches": 120, "ID": 12345678, "Lang": "en",
+                        "Name": "Tami Pesagniyah", "Rank": null, "RankIcon": null,
+                        "Server": "Omega"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let team = result.pvp_team;
+        assert_eq!(team.profile.data_center.as_deref(), Some("Chaos"));
+        assert_eq!(team.results[0].feast_matches, 120);
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use reqwest::Url;
+
+/// Builds request URLs for XIVAPI endpoints.
+///
+/// Path segments and query parameters are percent-encoded, so names containing spaces,
+/// apostrophes, ampersands or non-ASCII characters are sent intact.
+#[derive(Debug)]
+pub(crate) struct RequestUrl {
+    url: Url,
+}
+
+impl RequestUrl {
+    /// Starts a URL for the endpoint made up of `segments`, relative to `base`.
+    pub(crate) fn new(base: &Url, segments: &[&str]) -> Self {
+        let mut url = base.clone();
+        url.set_query(None);
+        url.set_fragment(None);
+
+        url.path_segments_mut()
+            .expect("base URL is validated by the builder")
+            .pop_if_empty()
+            .extend(segments);
+
+        RequestUrl { url }
+    }
+
+    /// Adds a query parameter.
+    pub(crate) fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.url
+            .query_pairs_mut()
+            .append_pair(name, &value.to_string());
+        self
+    }
+
+    /// Adds a query parameter if a value is given.
+    pub(crate) fn optional(self, name: &str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.param(name, value),
+            None => self,
+        }
+    }
+
+    /// Adds a `name=1` query parameter if `enabled` is true.
+    pub(crate) fn flag(self, name: &str, enabled: bool) -> Self {
+        if enabled {
+            self.param(name, 1)
+        } else {
+            self
+        }
+    }
+
+    /// Adds a comma-separated list as a single query parameter, if the list isn't empty.
+    pub(crate) fn list<I>(self, name: &str, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        let values: Vec<String> = values.into_iter().map(|value| value.to_string()).collect();
+
+        if values.is_empty() {
+            self
+        } else {
+            self.param(name, values.join(","))
+        }
+    }
+
+    /// Adds a name used for searching, collapsing runs of whitespace into single spaces.
+    pub(crate) fn name(self, name: &str) -> Self {
+        let name = name.split_whitespace().collect::<Vec<&str>>().join(" ");
+        self.param("name", name)
+    }
+
+    pub(crate) fn build(self) -> Url {
+        self.url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Url;
+
+    use super::RequestUrl;
+
+    fn base() -> Url {
+        Url::parse("https://xivapi.com").unwrap()
+    }
+
+    fn query_value(url: &Url, name: &str) -> String {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tricky_names() {
+        let names = [
+            "Tea & Biscuits",
+            "J'nya Sol'tarah",
+            "Mary-Ann O'Neil",
+            "Émilie Dûbois",
+            "Größe Straße",
+            "ヒカセン",
+            "100% Catgirl #1",
+        ];
+
+        for name in names {
+            let url = RequestUrl::new(&base(), &["freecompany", "search"])
+                .name(name)
+                .param("server", "Omega")
+                .build();
+
+            assert_eq!(query_value(&url, "name"), name);
+            assert_eq!(query_value(&url, "server"), "Omega");
+            assert_eq!(url.query_pairs().count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_ampersand_is_encoded() {
+        let url = RequestUrl::new(&base(), &["freecompany", "search"])
+            .name("Tea  &   Biscuits")
+            .build();
+
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/freecompany/search?name=Tea+%26+Biscuits"
+        );
+    }
+
+    #[test]
+    fn test_path_and_list() {
+        let base = Url::parse("http://localhost:8080/mirror/").unwrap();
+        let url = RequestUrl::new(&base, &["character", "12345678"])
+            .flag("extended", true)
+            .list("data", ["AC", "FR"])
+            .list("columns", Vec::<&str>::new())
+            .build();
+
+        assert_eq!(url.path(), "/mirror/character/12345678");
+        assert_eq!(query_value(&url, "extended"), "1");
+        assert_eq!(query_value(&url, "data"), "AC,FR");
+        assert!(url.query_pairs().all(|(key, _)| key != "columns"));
+
+        let url = RequestUrl::new(&base, &["character", "12345678"]).build();
+        assert_eq!(
+            url.as_str(),
+            "http://localhost:8080/mirror/character/12345678"
+        );
+    }
+}
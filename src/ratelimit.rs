@@ -0,0 +1,314 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{header::HeaderMap, StatusCode};
+
+/// The longest wait taken from a response header, so a bogus value can't block a key forever.
+pub(crate) const MAX_WAIT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Settings for the client-side rate limiter.
+///
+/// Requests that would exceed the limit are queued until a token is available instead of failing.
+/// When private keys are configured, every key gets its own bucket.
+///
+/// # Examples
+/// ```
+/// use xivapi_rust::{RateLimit, XIVAPIClient};
+///
+/// # fn main() -> Result<(), xivapi_rust::Error> {
+/// let client = XIVAPIClient::builder()
+///     .rate_limit(RateLimit::new(10.0, 20))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    requests_per_second: f64,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Creates a rate limit allowing `requests_per_second` on average, with up to `burst` requests at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is not positive or `burst` is zero.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second > 0.0,
+            "requests_per_second must be positive"
+        );
+        assert!(burst > 0, "burst must be at least 1");
+
+        RateLimit {
+            requests_per_second,
+            burst,
+        }
+    }
+
+    /// The average number of requests allowed per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// The maximum number of requests that can be sent at once.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+impl Default for RateLimit {
+    /// XIVAPI's documented limit of 20 requests per second.
+    fn default() -> Self {
+        RateLimit::new(20.0, 20)
+    }
+}
+
+/// A token bucket for a single private key (or for keyless requests).
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    last_refill: Instant,
+    blocked_until: Option<Instant>,
+}
+
+/// Token bucket rate limiter shared by all clones of an `XIVAPIClient`.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    buckets: Mutex<HashMap<Option<usize>, Bucket>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until a request may be sent for the given key, then takes a token.
+    pub(crate) async fn acquire(&self, key: Option<usize>) {
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let bucket = self.bucket(&mut buckets, key);
+                let now = Instant::now();
+
+                self.refill(bucket, now);
+
+                match bucket.blocked_until {
+                    Some(until) if until > now => until - now,
+                    _ if bucket.tokens >= 1.0 => {
+                        bucket.tokens -= 1.0;
+                        return;
+                    }
+                    _ => Duration::from_secs_f64(
+                        (1.0 - bucket.tokens) / self.limit.requests_per_second,
+                    ),
+                }
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Adjusts the bucket for a key based on the rate limit information in a response.
+    ///
+    /// Reads `X-RateLimit-Limit`, `X-RateLimit-Remaining`, `X-RateLimit-Reset` and, for
+    /// `429 Too Many Requests` responses, `Retry-After`.
+    pub(crate) fn observe(&self, key: Option<usize>, status: StatusCode, headers: &HeaderMap) {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = self.bucket(&mut buckets, key);
+        let now = Instant::now();
+
+        if let Some(limit) = header_number(headers, "x-ratelimit-limit") {
+            bucket.capacity = limit.clamp(1.0, self.limit.burst as f64);
+        }
+
+        if let Some(remaining) = header_number(headers, "x-ratelimit-remaining") {
+            bucket.tokens = bucket.tokens.min(remaining);
+
+            if remaining < 1.0 {
+                let reset = reset_after(headers).unwrap_or(Duration::from_secs(1));
+                bucket.blocked_until = now.checked_add(reset.min(MAX_WAIT));
+            }
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let wait = retry_after(headers).unwrap_or(Duration::from_secs(1));
+            bucket.tokens = 0.0;
+            bucket.blocked_until = now.checked_add(wait.min(MAX_WAIT));
+        }
+    }
+
+    fn bucket<'a>(
+        &self,
+        buckets: &'a mut HashMap<Option<usize>, Bucket>,
+        key: Option<usize>,
+    ) -> &'a mut Bucket {
+        buckets.entry(key).or_insert_with(|| Bucket {
+            tokens: self.limit.burst as f64,
+            capacity: self.limit.burst as f64,
+            last_refill: Instant::now(),
+            blocked_until: None,
+        })
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.limit.requests_per_second).min(bucket.capacity);
+        bucket.last_refill = now;
+    }
+}
+
+/// Reads the `Retry-After` header, given either as a number of seconds or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(seconds) = header_number(headers, reqwest::header::RETRY_AFTER.as_str()) {
+        return wait(seconds);
+    }
+
+    // HTTP dates, e.g. `Wed, 21 Oct 2015 07:28:00 GMT`, are a subset of RFC 2822.
+    let date = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    let date = chrono::DateTime::parse_from_rfc2822(date.trim()).ok()?;
+
+    Some(
+        (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default()
+            .min(MAX_WAIT),
+    )
+}
+
+/// Reads `X-RateLimit-Reset`, which may be either a number of seconds or a Unix timestamp.
+fn reset_after(headers: &HeaderMap) -> Option<Duration> {
+    let reset = header_number(headers, "x-ratelimit-reset")?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_secs_f64();
+
+    // Anything larger than a day can only be a timestamp.
+    let seconds = if reset > 86_400.0 { reset - now } else { reset };
+
+    wait(seconds)
+}
+
+/// Converts a number of seconds from a header, clamped between zero and `MAX_WAIT`.
+fn wait(seconds: f64) -> Option<Duration> {
+    if seconds.is_nan() {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(
+        seconds.clamp(0.0, MAX_WAIT.as_secs_f64()),
+    ))
+}
+
+fn header_number(headers: &HeaderMap, name: &str) -> Option<f64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+
+    use super::{retry_after, RateLimit, RateLimiter, MAX_WAIT};
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_tokens() {
+        let limiter = RateLimiter::new(RateLimit::new(20.0, 2));
+        let start = Instant::now();
+
+        for _ in 0..4 {
+            limiter.acquire(None).await;
+        }
+
+        // Two requests fit in the burst, the other two wait 50ms each.
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_buckets_are_per_key() {
+        let limiter = RateLimiter::new(RateLimit::new(1.0, 1));
+        let start = Instant::now();
+
+        limiter.acquire(Some(0)).await;
+        limiter.acquire(Some(1)).await;
+
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_observe_remaining_blocks_bucket() {
+        let limiter = RateLimiter::new(RateLimit::new(100.0, 10));
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("0.2"));
+
+        limiter.observe(None, StatusCode::OK, &headers);
+
+        let start = Instant::now();
+        limiter.acquire(None).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert("retry-after", HeaderValue::from_static("120"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+        let date = (chrono::Utc::now() + chrono::Duration::seconds(60)).to_rfc2822();
+        headers.insert("retry-after", HeaderValue::from_str(&date).unwrap());
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(55) && wait <= Duration::from_secs(60));
+
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert("retry-after", HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert("retry-after", HeaderValue::from_static("NaN"));
+        assert_eq!(retry_after(&headers), None);
+
+        for huge in ["inf", "1e300", "99999999999999999999"] {
+            headers.insert("retry-after", HeaderValue::from_static(huge));
+            assert_eq!(retry_after(&headers), Some(MAX_WAIT));
+        }
+
+        headers.insert("retry-after", HeaderValue::from_static("-5"));
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_observe_huge_headers() {
+        let limiter = RateLimiter::new(RateLimit::new(100.0, 10));
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("inf"));
+        headers.insert("retry-after", HeaderValue::from_static("1e300"));
+
+        limiter.observe(None, StatusCode::OK, &headers);
+        limiter.observe(None, StatusCode::TOO_MANY_REQUESTS, &headers);
+    }
+}
@@ -0,0 +1,1256 @@
+use futures::stream::BoxStream;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    cache::Endpoint,
+    character::{
+        data::Membership, CharacterData, CharacterResult, CharacterSearch, CharacterSearchResults,
+        ExtendedCharacterResult,
+    },
+    freecompany::{FreeCompanyResult, FreeCompanySearch, FreeCompanySearchResults},
+    linkshell::{LinkshellResult, LinkshellSearch, LinkshellSearchResults},
+    lodestone::{MaintenanceWindow, NewsCategory, NewsItem, RawNewsItem},
+    pagination::{self, Paged},
+    patch::{self, Patch, RawPatch},
+    pvpteam::{PvPTeamResult, PvPTeamSearch, PvPTeamSearchResults},
+    query::RequestUrl,
+    sheet::SheetPage,
+    world::{self, DataCenter, DATA_CENTER_PREFIX},
+    Cached, Error, Language, XIVAPIClient,
+};
+
+/// Query parameters shared by the request builders.
+#[derive(Clone, Debug)]
+struct CommonParams {
+    page: Option<u32>,
+    columns: Vec<String>,
+    language: Option<Language>,
+    max_pages: Option<u32>,
+    prefetch: bool,
+}
+
+impl Default for CommonParams {
+    fn default() -> Self {
+        CommonParams {
+            page: None,
+            columns: Vec::new(),
+            language: None,
+            max_pages: None,
+            prefetch: true,
+        }
+    }
+}
+
+impl CommonParams {
+    /// Adds the page, columns and language, falling back to the client's default language.
+    fn apply(&self, url: RequestUrl, client: &XIVAPIClient) -> RequestUrl {
+        url.optional("page", self.page)
+            .list("columns", &self.columns)
+            .optional("language", self.language.or(client.language))
+    }
+}
+
+/// Generates the setters for the given `CommonParams` of a builder with a `params` field.
+///
+/// `columns` takes an example column for its documentation.
+macro_rules! common_params {
+    ($($setter:ident $(($example:literal))?),* $(,)?) => {
+        $(common_params!(@$setter $($example)?);)*
+    };
+    (@page) => {
+        /// Requests a specific page of results.
+        pub fn page(mut self, page: u32) -> Self {
+            self.params.page = Some(page);
+            self
+        }
+    };
+    (@language) => {
+        /// Localizes the response in the given language, instead of the client's default.
+        pub fn language(mut self, language: Language) -> Self {
+            self.params.language = Some(language);
+            self
+        }
+    };
+    (@columns $example:literal) => {
+        #[doc = concat!("Limits the response to the given fields, e.g. `", $example, "`.")]
+        ///
+        /// The response will be missing the other fields, so decode it into a type that doesn't
+        /// require them.
+        pub fn columns<I, S>(mut self, columns: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+        {
+            self.params.columns = columns.into_iter().map(Into::into).collect();
+            self
+        }
+    };
+    (@max_pages) => {
+        /// Limits `stream()` to the given number of pages.
+        pub fn max_pages(mut self, max_pages: u32) -> Self {
+            self.params.max_pages = Some(max_pages);
+            self
+        }
+    };
+    (@prefetch) => {
+        /// If true, `stream()` requests the next page while the current one is being consumed.
+        ///
+        /// Enabled by default.
+        pub fn prefetch(mut self, prefetch: bool) -> Self {
+            self.params.prefetch = prefetch;
+            self
+        }
+    };
+}
+
+/// Implements `Paged` for a search builder with `CommonParams` and a `send()` method.
+macro_rules! paged {
+    ($($request:ty => $page:ty),* $(,)?) => {$(
+        impl Paged for $request {
+            type Page = $page;
+
+            fn pages(&self) -> (u32, Option<u32>, bool) {
+                let params = &self.params;
+                (params.page.unwrap_or(1), params.max_pages, params.prefetch)
+            }
+
+            fn with_page(self, page: u32) -> Self {
+                self.page(page)
+            }
+
+            async fn send_page(self, client: XIVAPIClient) -> Result<$page, Error> {
+                self.send(&client).await
+            }
+        }
+    )*};
+}
+
+paged! {
+    CharacterSearchRequest => CharacterSearchResults,
+    FreeCompanySearchRequest => FreeCompanySearchResults,
+    LinkshellSearchRequest => LinkshellSearchResults,
+    PvPTeamSearchRequest => PvPTeamSearchResults,
+}
+
+/// A name-based character search.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::CharacterSearchRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = CharacterSearchRequest::new("Scott")
+///         .server("Omega")
+///         .page(2)
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CharacterSearchRequest {
+    name: String,
+    server: Option<String>,
+    params: CommonParams,
+}
+
+impl CharacterSearchRequest {
+    /// Creates a search for characters named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        CharacterSearchRequest {
+            name: name.into(),
+            server: None,
+            params: CommonParams::default(),
+        }
+    }
+
+    /// Only returns characters on the given server.
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    /// Only returns characters on the worlds of the given data center.
+    ///
+    /// Replaces any server set with `server()`.
+    pub fn data_center(mut self, data_center: &str) -> Self {
+        self.server = Some(format!("{}{}", DATA_CENTER_PREFIX, data_center));
+        self
+    }
+
+    common_params!(page, language, columns("Results.*.ID"), max_pages, prefetch);
+
+    /// Sends the search to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `CharacterSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<CharacterSearchResults, Error> {
+        self.send_as(client).await
+    }
+
+    /// Streams the characters on every page of the search, starting from `page()` or the first page.
+    ///
+    /// The stream ends after the last page, after `max_pages()` pages, or after the first error.
+    pub fn stream(
+        &self,
+        client: &XIVAPIClient,
+    ) -> BoxStream<'static, Result<CharacterSearch, Error>> {
+        pagination::stream_pages(self, client)
+    }
+
+    /// Sends the search to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client
+            .validate_server(self.server.as_deref(), false)
+            .await?;
+        client.get(Endpoint::Search, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&["character", "search"])
+            .name(&self.name)
+            .optional("server", self.server.as_ref());
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A character lookup by ID.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{character::CharacterData, request::CharacterLookupRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = CharacterLookupRequest::new(12345678)
+///         .data(CharacterData::ACHIEVEMENTS | CharacterData::FRIENDS)
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CharacterLookupRequest {
+    character_id: u32,
+    extended: bool,
+    data: CharacterData,
+    params: CommonParams,
+}
+
+impl CharacterLookupRequest {
+    /// Creates a lookup for the character with the given ID.
+    pub fn new(character_id: u32) -> Self {
+        CharacterLookupRequest {
+            character_id,
+            extended: false,
+            data: CharacterData::empty(),
+            params: CommonParams::default(),
+        }
+    }
+
+    /// If true, extends out the data IDs of useful objects.
+    ///
+    /// The expanded objects don't fit `CharacterResult`, so use `send_extended()` to decode them.
+    pub fn extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// Requests additional sections, such as achievements or the friend list.
+    ///
+    /// `send()` fails with `Error::MissingSections` if XIVAPI leaves out a requested section,
+    /// so the matching fields of the result can be relied on. They're only `None` when the
+    /// section is private, or the character has no Free Company or PvP team.
+    pub fn data(mut self, data: CharacterData) -> Self {
+        self.data = data;
+        self
+    }
+
+    common_params!(language, columns("Character.Name"));
+
+    /// Sends the lookup to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing character information as `CharacterResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<CharacterResult, Error> {
+        self.send_cached(client).await.map(Cached::into_inner)
+    }
+
+    /// Sends the lookup to XIVAPI, also reporting whether the result came from the cache.
+    pub async fn send_cached(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<Cached<CharacterResult>, Error> {
+        self.lookup(client).await
+    }
+
+    /// Sends the lookup to XIVAPI with `extended` enabled, decoding the expanded objects.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing character information as `ExtendedCharacterResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send_extended(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<ExtendedCharacterResult, Error> {
+        self.send_extended_cached(client)
+            .await
+            .map(Cached::into_inner)
+    }
+
+    /// Same as `send_extended()`, but also reports whether the result came from the cache.
+    pub async fn send_extended_cached(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<Cached<ExtendedCharacterResult>, Error> {
+        self.clone().extended(true).lookup(client).await
+    }
+
+    /// Sends the lookup to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.get(Endpoint::Character, self.url(client)).await
+    }
+
+    async fn lookup<C>(&self, client: &XIVAPIClient) -> Result<Cached<CharacterResult<C>>, Error>
+    where
+        C: DeserializeOwned + Membership,
+    {
+        let result: Cached<CharacterResult<C>> = client
+            .get_cached(Endpoint::Character, self.url(client))
+            .await?;
+
+        let missing = result.data.missing_sections(self.data);
+
+        if missing.is_empty() {
+            Ok(result)
+        } else {
+            Err(Error::MissingSections(missing))
+        }
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&["character", &self.character_id.to_string()])
+            .flag("extended", self.extended)
+            .list("data", self.data.codes());
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A name-based Free Company search.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::FreeCompanySearchRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = FreeCompanySearchRequest::new("Tea & Biscuits")
+///         .server("Phoenix")
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FreeCompanySearchRequest {
+    name: String,
+    server: Option<String>,
+    params: CommonParams,
+}
+
+impl FreeCompanySearchRequest {
+    /// Creates a search for Free Companies named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        FreeCompanySearchRequest {
+            name: name.into(),
+            server: None,
+            params: CommonParams::default(),
+        }
+    }
+
+    /// Only returns Free Companies on the given server.
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    /// Only returns Free Companies on the worlds of the given data center.
+    ///
+    /// Replaces any server set with `server()`.
+    pub fn data_center(mut self, data_center: &str) -> Self {
+        self.server = Some(format!("{}{}", DATA_CENTER_PREFIX, data_center));
+        self
+    }
+
+    common_params!(page, language, columns("Results.*.ID"), max_pages, prefetch);
+
+    /// Sends the search to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `FreeCompanySearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<FreeCompanySearchResults, Error> {
+        self.send_as(client).await
+    }
+
+    /// Streams the Free Companies on every page of the search, starting from `page()` or the first page.
+    ///
+    /// The stream ends after the last page, after `max_pages()` pages, or after the first error.
+    pub fn stream(
+        &self,
+        client: &XIVAPIClient,
+    ) -> BoxStream<'static, Result<FreeCompanySearch, Error>> {
+        pagination::stream_pages(self, client)
+    }
+
+    /// Sends the search to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client
+            .validate_server(self.server.as_deref(), false)
+            .await?;
+        client.get(Endpoint::Search, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&["freecompany", "search"])
+            .name(&self.name)
+            .optional("server", self.server.as_ref());
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A Free Company lookup by ID.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::FreeCompanyLookupRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = FreeCompanyLookupRequest::new("9228438586435659999")
+///         .data(["FCM"])
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FreeCompanyLookupRequest {
+    free_company_id: String,
+    extended: bool,
+    data: Vec<String>,
+    params: CommonParams,
+}
+
+impl FreeCompanyLookupRequest {
+    /// Creates a lookup for the Free Company with the given ID.
+    pub fn new(free_company_id: impl Into<String>) -> Self {
+        FreeCompanyLookupRequest {
+            free_company_id: free_company_id.into(),
+            extended: false,
+            data: Vec::new(),
+            params: CommonParams::default(),
+        }
+    }
+
+    /// If true, extends out additional data IDs of useful objects.
+    pub fn extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// Requests additional information, such as `FCM` for the member list.
+    pub fn data<I, S>(mut self, data: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.data = data.into_iter().map(Into::into).collect();
+        self
+    }
+
+    common_params!(language, columns("FreeCompany.Name"));
+
+    /// Sends the lookup to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing detailed information about the Free Company as `FreeCompanyResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<FreeCompanyResult, Error> {
+        self.send_as(client).await
+    }
+
+    /// Sends the lookup to XIVAPI, also reporting whether the result came from the cache.
+    pub async fn send_cached(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<Cached<FreeCompanyResult>, Error> {
+        client
+            .get_cached(Endpoint::FreeCompany, self.url(client))
+            .await
+    }
+
+    /// Sends the lookup to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.get(Endpoint::FreeCompany, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&["freecompany", &self.free_company_id])
+            .flag("extended", self.extended)
+            .list("data", &self.data);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A name-based linkshell or cross-world linkshell search.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::LinkshellSearchRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = LinkshellSearchRequest::new("Static Night")
+///         .crossworld(true)
+///         .server("Chaos")
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinkshellSearchRequest {
+    name: String,
+    crossworld: bool,
+    server: Option<String>,
+    params: CommonParams,
+}
+
+impl LinkshellSearchRequest {
+    /// Creates a search for linkshells named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        LinkshellSearchRequest {
+            name: name.into(),
+            crossworld: false,
+            server: None,
+            params: CommonParams::default(),
+        }
+    }
+
+    /// If true, searches cross-world linkshells instead of linkshells.
+    pub fn crossworld(mut self, crossworld: bool) -> Self {
+        self.crossworld = crossworld;
+        self
+    }
+
+    /// Only returns linkshells on the given server, or cross-world linkshells on the given data center.
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    common_params!(page, language, columns("Results.*.ID"), max_pages, prefetch);
+
+    /// Sends the search to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `LinkshellSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<LinkshellSearchResults, Error> {
+        self.send_as(client).await
+    }
+
+    /// Streams the linkshells on every page of the search, starting from `page()` or the first page.
+    ///
+    /// The stream ends after the last page, after `max_pages()` pages, or after the first error.
+    pub fn stream(
+        &self,
+        client: &XIVAPIClient,
+    ) -> BoxStream<'static, Result<LinkshellSearch, Error>> {
+        pagination::stream_pages(self, client)
+    }
+
+    /// Sends the search to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client
+            .validate_server(self.server.as_deref(), self.crossworld)
+            .await?;
+        client.get(Endpoint::Search, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let segments: &[&str] = if self.crossworld {
+            &["linkshell", "crossworld", "search"]
+        } else {
+            &["linkshell", "search"]
+        };
+
+        let url = client
+            .url(segments)
+            .name(&self.name)
+            .optional("server", self.server.as_ref());
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A linkshell or cross-world linkshell lookup by ID.
+///
+/// Members are returned 50 at a time, so larger linkshells need a lookup for every page.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::LinkshellLookupRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = LinkshellLookupRequest::new("f1b0b5c6d4b5d1e2a8f1c1e6f4b6c7e4a8b5c9d1")
+///         .crossworld(true)
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinkshellLookupRequest {
+    linkshell_id: String,
+    crossworld: bool,
+    params: CommonParams,
+}
+
+impl LinkshellLookupRequest {
+    /// Creates a lookup for the linkshell with the given ID.
+    pub fn new(linkshell_id: impl Into<String>) -> Self {
+        LinkshellLookupRequest {
+            linkshell_id: linkshell_id.into(),
+            crossworld: false,
+            params: CommonParams::default(),
+        }
+    }
+
+    /// If true, looks up a cross-world linkshell instead of a linkshell.
+    pub fn crossworld(mut self, crossworld: bool) -> Self {
+        self.crossworld = crossworld;
+        self
+    }
+
+    common_params!(page, language, columns("Linkshell.Profile.Name"));
+
+    /// Sends the lookup to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the linkshell and a page of its members as `LinkshellResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<LinkshellResult, Error> {
+        self.send_as(client).await
+    }
+
+    /// Sends the lookup to XIVAPI, also reporting whether the result came from the cache.
+    pub async fn send_cached(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<Cached<LinkshellResult>, Error> {
+        client
+            .get_cached(Endpoint::Linkshell, self.url(client))
+            .await
+    }
+
+    /// Sends the lookup to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.get(Endpoint::Linkshell, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let segments: &[&str] = if self.crossworld {
+            &["linkshell", "crossworld", &self.linkshell_id]
+        } else {
+            &["linkshell", &self.linkshell_id]
+        };
+
+        let url = client.url(segments);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A name-based PvP team search.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::PvPTeamSearchRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = PvPTeamSearchRequest::new("Feast Mode")
+///         .server("Chaos")
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PvPTeamSearchRequest {
+    name: String,
+    server: Option<String>,
+    params: CommonParams,
+}
+
+impl PvPTeamSearchRequest {
+    /// Creates a search for PvP teams named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        PvPTeamSearchRequest {
+            name: name.into(),
+            server: None,
+            params: CommonParams::default(),
+        }
+    }
+
+    /// Only returns PvP teams on the given data center.
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    common_params!(page, language, columns("Results.*.ID"), max_pages, prefetch);
+
+    /// Sends the search to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the search results as `PvPTeamSearchResults` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<PvPTeamSearchResults, Error> {
+        self.send_as(client).await
+    }
+
+    /// Streams the PvP teams on every page of the search, starting from `page()` or the first page.
+    ///
+    /// The stream ends after the last page, after `max_pages()` pages, or after the first error.
+    pub fn stream(
+        &self,
+        client: &XIVAPIClient,
+    ) -> BoxStream<'static, Result<PvPTeamSearch, Error>> {
+        pagination::stream_pages(self, client)
+    }
+
+    /// Sends the search to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.validate_server(self.server.as_deref(), true).await?;
+        client.get(Endpoint::Search, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&["pvpteam", "search"])
+            .name(&self.name)
+            .optional("server", self.server.as_ref());
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A PvP team lookup by ID.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::PvPTeamLookupRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let result = PvPTeamLookupRequest::new("8c3ad7d3d8f6a4c55b5a2a3a2a4c7e1b2f7d3a8e")
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PvPTeamLookupRequest {
+    pvp_team_id: String,
+    params: CommonParams,
+}
+
+impl PvPTeamLookupRequest {
+    /// Creates a lookup for the PvP team with the given ID.
+    pub fn new(pvp_team_id: impl Into<String>) -> Self {
+        PvPTeamLookupRequest {
+            pvp_team_id: pvp_team_id.into(),
+            params: CommonParams::default(),
+        }
+    }
+
+    common_params!(language, columns("PvPTeam.Profile.Name"));
+
+    /// Sends the lookup to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the PvP team and its members as `PvPTeamResult` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<PvPTeamResult, Error> {
+        self.send_as(client).await
+    }
+
+    /// Sends the lookup to XIVAPI, also reporting whether the result came from the cache.
+    pub async fn send_cached(&self, client: &XIVAPIClient) -> Result<Cached<PvPTeamResult>, Error> {
+        client.get_cached(Endpoint::PvPTeam, self.url(client)).await
+    }
+
+    /// Sends the lookup to XIVAPI and decodes the response into a custom type.
+    pub async fn send_as<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.get(Endpoint::PvPTeam, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client.url(&["pvpteam", &self.pvp_team_id]);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A page of rows from a game data sheet, such as `Item` or `Mount`.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{request::SheetRowsRequest, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let page = SheetRowsRequest::new("Item")
+///         .ids([1, 2, 3])
+///         .columns(["ID", "Name"])
+///         .send::<serde_json::Value>(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SheetRowsRequest {
+    sheet: String,
+    limit: Option<u32>,
+    ids: Vec<u32>,
+    params: CommonParams,
+}
+
+impl SheetRowsRequest {
+    /// Creates a request for the rows of the given sheet, e.g. `Item`.
+    pub fn new(sheet: impl Into<String>) -> Self {
+        SheetRowsRequest {
+            sheet: sheet.into(),
+            limit: None,
+            ids: Vec::new(),
+            params: CommonParams::default(),
+        }
+    }
+
+    common_params!(page, language, columns("ClassJobCategory.Name"));
+
+    /// Sets the maximum number of rows on each page. XIVAPI allows up to 3000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only requests the rows with the given IDs.
+    pub fn ids<I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        self.ids = ids.into_iter().collect();
+        self
+    }
+
+    /// Sends the request to XIVAPI, decoding each row into `T`.
+    ///
+    /// Use `serde_json::Value` for `T` to keep the rows untyped.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the rows as `SheetPage<T>` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send<T: DeserializeOwned>(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<SheetPage<T>, Error> {
+        client.get(Endpoint::GameData, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client
+            .url(&[&self.sheet])
+            .optional("limit", self.limit)
+            .list("ids", &self.ids);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// A single row of a game data sheet, such as `Item/1675`.
+#[derive(Clone, Debug)]
+pub struct SheetRowRequest {
+    sheet: String,
+    id: u32,
+    params: CommonParams,
+}
+
+impl SheetRowRequest {
+    /// Creates a request for the row with the given ID of the given sheet.
+    pub fn new(sheet: impl Into<String>, id: u32) -> Self {
+        SheetRowRequest {
+            sheet: sheet.into(),
+            id,
+            params: CommonParams::default(),
+        }
+    }
+
+    common_params!(language, columns("ClassJobCategory.Name"));
+
+    /// Sends the request to XIVAPI, decoding the row into `T`.
+    ///
+    /// Use `serde_json::Value` for `T` to keep the row untyped.
+    pub async fn send<T: DeserializeOwned>(&self, client: &XIVAPIClient) -> Result<T, Error> {
+        client.get(Endpoint::GameData, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client.url(&[&self.sheet, &self.id.to_string()]);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// The latest entries of a Lodestone news feed.
+///
+/// # Examples
+/// ```no_run
+/// use xivapi_rust::{lodestone::NewsCategory, request::NewsRequest, Language, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///
+///     let news = NewsRequest::new(NewsCategory::Topics)
+///         .language(Language::German)
+///         .send(&client)
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NewsRequest {
+    category: NewsCategory,
+    params: CommonParams,
+}
+
+impl NewsRequest {
+    /// Creates a request for the given feed.
+    pub fn new(category: NewsCategory) -> Self {
+        NewsRequest {
+            category,
+            params: CommonParams::default(),
+        }
+    }
+
+    common_params!(language);
+
+    /// Sends the request to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the entries as `Vec<NewsItem>`, newest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<Vec<NewsItem>, Error> {
+        let items = self.feed(client).await?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| item.into_item(self.category))
+            .collect())
+    }
+
+    /// Sends the request to XIVAPI, keeping the start and end times of each entry.
+    ///
+    /// Only entries of the `NewsCategory::Maintenance` feed have start and end times.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the entries as `Vec<MaintenanceWindow>`, newest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send_maintenance(
+        &self,
+        client: &XIVAPIClient,
+    ) -> Result<Vec<MaintenanceWindow>, Error> {
+        let items = self.feed(client).await?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| item.into_window(self.category))
+            .collect())
+    }
+
+    async fn feed(&self, client: &XIVAPIClient) -> Result<Vec<RawNewsItem>, Error> {
+        client.get(Endpoint::Lodestone, self.url(client)).await
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client.url(&["lodestone", self.category.path()]);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// The list of every game patch.
+#[derive(Clone, Debug, Default)]
+pub struct PatchListRequest {
+    params: CommonParams,
+}
+
+impl PatchListRequest {
+    /// Creates a request for the patch list.
+    pub fn new() -> Self {
+        PatchListRequest::default()
+    }
+
+    common_params!(language);
+
+    /// Sends the request to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the patches as `Vec<Patch>`, oldest first, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<Vec<Patch>, Error> {
+        let patches: Vec<RawPatch> = client.get(Endpoint::GameData, self.url(client)).await?;
+
+        Ok(patch::sorted(patches))
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client.url(&["patchlist"]);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+/// The list of every data center and the worlds it hosts.
+#[derive(Clone, Debug, Default)]
+pub struct DataCentersRequest {
+    params: CommonParams,
+}
+
+impl DataCentersRequest {
+    /// Creates a request for the data centers.
+    pub fn new() -> Self {
+        DataCentersRequest::default()
+    }
+
+    common_params!(language);
+
+    /// Sends the request to XIVAPI.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the data centers as `Vec<DataCenter>`, sorted by name, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn send(&self, client: &XIVAPIClient) -> Result<Vec<DataCenter>, Error> {
+        let data_centers = client.get(Endpoint::GameData, self.url(client)).await?;
+
+        Ok(world::data_centers(data_centers))
+    }
+
+    fn url(&self, client: &XIVAPIClient) -> Url {
+        let url = client.url(&["servers", "dc"]);
+
+        self.params.apply(url, client).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CharacterLookupRequest, CharacterSearchRequest, DataCentersRequest,
+        FreeCompanyLookupRequest, LinkshellLookupRequest, LinkshellSearchRequest, NewsRequest,
+        PatchListRequest, SheetRowRequest, SheetRowsRequest,
+    };
+    use crate::{character::CharacterData, lodestone::NewsCategory, Error, Language, XIVAPIClient};
+
+    #[test]
+    fn test_search_url() {
+        let client = XIVAPIClient::new();
+        let url = CharacterSearchRequest::new("Tami Pesagniyah")
+            .server("Omega")
+            .page(2)
+            .url(&client);
+
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/character/search?name=Tami+Pesagniyah&server=Omega&page=2"
+        );
+    }
+
+    #[test]
+    fn test_lookup_url() {
+        let client = XIVAPIClient::new();
+
+        let url = CharacterLookupRequest::new(12345678)
+            .extended(true)
+            .data(CharacterData::ACHIEVEMENTS | CharacterData::FRIENDS)
+            .columns(["Character.Name"])
+            .url(&client);
+
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/character/12345678?extended=1&data=AC%2CFR&columns=Character.Name"
+        );
+
+        let url = FreeCompanyLookupRequest::new("9228438586435659999").url(&client);
+
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/freecompany/9228438586435659999"
+        );
+    }
+
+    #[test]
+    fn test_language_url() -> Result<(), Error> {
+        let client = XIVAPIClient::builder()
+            .language(Language::Japanese)
+            .build()?;
+
+        let url = CharacterLookupRequest::new(12345678).url(&client);
+        assert_eq!(url.query(), Some("language=ja"));
+
+        let url = CharacterLookupRequest::new(12345678)
+            .language(Language::French)
+            .url(&client);
+        assert_eq!(url.query(), Some("language=fr"));
+
+        let url = CharacterLookupRequest::new(12345678).url(&XIVAPIClient::new());
+        assert_eq!(url.query(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_linkshell_urls() {
+        let client = XIVAPIClient::new();
+
+        let url = LinkshellSearchRequest::new("Static Night")
+            .crossworld(true)
+            .server("Chaos")
+            .url(&client);
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/linkshell/crossworld/search?name=Static+Night&server=Chaos"
+        );
+
+        let url = LinkshellLookupRequest::new("19984723346535274")
+            .page(2)
+            .url(&client);
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/linkshell/19984723346535274?page=2"
+        );
+    }
+
+    #[test]
+    fn test_sheet_urls() {
+        let client = XIVAPIClient::new();
+
+        let url = SheetRowsRequest::new("Item")
+            .page(2)
+            .limit(500)
+            .ids([1, 2, 3])
+            .columns(["ID", "Name"])
+            .url(&client);
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/Item?limit=500&ids=1%2C2%2C3&page=2&columns=ID%2CName"
+        );
+
+        let url = SheetRowRequest::new("Mount", 1)
+            .language(Language::German)
+            .url(&client);
+        assert_eq!(url.as_str(), "https://xivapi.com/Mount/1?language=de");
+    }
+
+    #[test]
+    fn test_game_data_urls() -> Result<(), Error> {
+        let client = XIVAPIClient::builder()
+            .language(Language::Japanese)
+            .build()?;
+
+        let url = NewsRequest::new(NewsCategory::Maintenance).url(&client);
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/lodestone/maintenance?language=ja"
+        );
+
+        let url = NewsRequest::new(NewsCategory::Topics)
+            .language(Language::German)
+            .url(&client);
+        assert_eq!(
+            url.as_str(),
+            "https://xivapi.com/lodestone/news?language=de"
+        );
+
+        let url = PatchListRequest::new()
+            .language(Language::French)
+            .url(&client);
+        assert_eq!(url.as_str(), "https://xivapi.com/patchlist?language=fr");
+
+        let url = DataCentersRequest::new()
+            .language(Language::English)
+            .url(&client);
+        assert_eq!(url.as_str(), "https://xivapi.com/servers/dc?language=en");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+use crate::Error;
+
+/// How random jitter is applied to the backoff between retries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Jitter {
+    /// Wait exactly the computed backoff.
+    None,
+    /// Wait a random duration between zero and the computed backoff.
+    Full,
+    /// Wait half of the computed backoff plus a random duration up to the other half.
+    Equal,
+}
+
+/// Controls if and how failed requests are retried.
+///
+/// Applied to every endpoint once configured with `XIVAPIClientBuilder::retry()`.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use xivapi_rust::{RetryPolicy, XIVAPIClient};
+///
+/// # fn main() -> Result<(), xivapi_rust::Error> {
+/// let client = XIVAPIClient::builder()
+///     .retry(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_millis(250)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: Jitter,
+    respect_retry_after: bool,
+    retry_timeouts: bool,
+    retryable_statuses: Vec<StatusCode>,
+}
+
+impl RetryPolicy {
+    /// Creates the default policy: 3 attempts, exponential backoff starting at 500ms with full jitter,
+    /// honoring `Retry-After`, and retrying timeouts as well as 429, 502, 503 and 504 responses.
+    pub fn new() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: Jitter::Full,
+            respect_retry_after: true,
+            retry_timeouts: true,
+            retryable_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+
+    /// Sets the total number of attempts, including the first one. A value of 1 disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound for the backoff between retries, including waits asked for by
+    /// `Retry-After`.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets the factor the backoff grows by after each retry.
+    ///
+    /// Values below 1, and values that aren't finite, are replaced by 1.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = if multiplier.is_finite() {
+            multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        self
+    }
+
+    /// Sets how random jitter is applied to the backoff.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether the `Retry-After` header replaces the computed backoff when present.
+    ///
+    /// The wait is still capped by `max_backoff()`.
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Sets whether timeouts and connection failures are retried.
+    pub fn retry_timeouts(mut self, retry: bool) -> Self {
+        self.retry_timeouts = retry;
+        self
+    }
+
+    /// Sets the HTTP statuses that are retried.
+    pub fn retryable_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Whether a request that failed with `error` on attempt number `attempt` should be sent again.
+    pub(crate) fn should_retry(&self, error: &Error, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        match error {
+            Error::Request(error) if error.is_timeout() || error.is_connect() => {
+                self.retry_timeouts
+            }
+            error => error
+                .status()
+                .is_some_and(|status| self.retryable_statuses.contains(&status)),
+        }
+    }
+
+    /// How long to wait before sending attempt number `attempt + 1`.
+    pub(crate) fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let (true, Some(retry_after)) = (self.respect_retry_after, retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+
+        // Computed in f64 and capped before converting, since the growth overflows `Duration`
+        // after enough attempts.
+        let backoff = if self.initial_backoff.is_zero() {
+            Duration::ZERO
+        } else {
+            let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+            let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+            Duration::from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()))
+        };
+
+        match self.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => backoff.mul_f64(rand::thread_rng().gen_range(0.0..=1.0)),
+            Jitter::Equal => {
+                let half = backoff / 2;
+                half + half.mul_f64(rand::thread_rng().gen_range(0.0..=1.0))
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
+    use super::{Jitter, RetryPolicy};
+    use crate::Error;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .jitter(Jitter::None);
+
+        assert_eq!(policy.backoff(1, None), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2, None), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3, None), Duration::from_millis(300));
+        assert_eq!(
+            policy.backoff(3, Some(Duration::from_millis(50))),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            policy.backoff(1, Some(Duration::from_secs(5))),
+            Duration::from_millis(300)
+        );
+    }
+
+    #[test]
+    fn test_backoff_does_not_overflow() {
+        let policy = RetryPolicy::new()
+            .max_attempts(u32::MAX)
+            .initial_backoff(Duration::MAX)
+            .jitter(Jitter::None);
+
+        assert_eq!(policy.backoff(1, None), Duration::from_secs(30));
+        assert_eq!(policy.backoff(u32::MAX, None), Duration::from_secs(30));
+
+        let policy = RetryPolicy::new()
+            .multiplier(f64::INFINITY)
+            .jitter(Jitter::None);
+        assert_eq!(policy.backoff(5, None), Duration::from_millis(500));
+
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::ZERO)
+            .jitter(Jitter::None);
+        assert_eq!(policy.backoff(u32::MAX, None), Duration::ZERO);
+
+        let policy = RetryPolicy::new().jitter(Jitter::Equal);
+        assert!(policy.backoff(2_000, None) <= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        let unavailable = Error::Status {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            url: "https://xivapi.com".to_string(),
+        };
+        let not_found = Error::Status {
+            status: StatusCode::NOT_FOUND,
+            url: "https://xivapi.com".to_string(),
+        };
+
+        assert!(policy.should_retry(&unavailable, 1));
+        assert!(!policy.should_retry(&unavailable, 2));
+        assert!(!policy.should_retry(&not_found, 1));
+    }
+}
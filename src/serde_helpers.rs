@@ -0,0 +1,16 @@
+use serde::{Deserialize, Deserializer};
+
+/// A flag given as a boolean or as `0`/`1`.
+pub(crate) fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        Number(u8),
+    }
+
+    Ok(match Flag::deserialize(deserializer)? {
+        Flag::Bool(flag) => flag,
+        Flag::Number(flag) => flag != 0,
+    })
+}
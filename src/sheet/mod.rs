@@ -0,0 +1,206 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    cache::Endpoint,
+    pagination::Pagination,
+    request::{SheetRowRequest, SheetRowsRequest},
+    Error, XIVAPIClient,
+};
+
+/// The most rows `sheet_rows_by_ids()` requests at once.
+const MAX_IDS: usize = 500;
+
+/// A page of rows from a game data sheet.
+///
+/// Rows are kept as untyped `serde_json::Value`s unless a type is given.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SheetPage<T = serde_json::Value> {
+    /// Pagination information for the rows.
+    pub pagination: Pagination,
+    /// The rows on the requested page.
+    pub results: Vec<T>,
+}
+
+impl XIVAPIClient {
+    /// Lists the names of every game data sheet XIVAPI serves, e.g. `Item` or `Mount`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sheet names as `Vec<String>` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn content_list(&self) -> Result<Vec<String>, Error> {
+        let url = self.url(&["content"]).build();
+
+        self.get(Endpoint::GameData, url).await
+    }
+
+    /// Gets a page of rows from a game data sheet.
+    ///
+    /// Use `SheetRowsRequest` to request specific rows by ID or limit the columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The name of the sheet, e.g. `Item`.
+    /// * `page` - An optional page number for paginated results.
+    /// * `limit` - An optional maximum number of rows on each page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the rows, decoded into `T`, as `SheetPage<T>` or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn sheet_rows<T: DeserializeOwned>(
+        &self,
+        sheet: &str,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<SheetPage<T>, Error> {
+        let mut request = SheetRowsRequest::new(sheet);
+
+        if let Some(page) = page {
+            request = request.page(page);
+        }
+
+        if let Some(limit) = limit {
+            request = request.limit(limit);
+        }
+
+        request.send(self).await
+    }
+
+    /// Gets several rows of a game data sheet by ID, in as few requests as possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The name of the sheet, e.g. `Item`.
+    /// * `ids` - The IDs of the rows.
+    /// * `columns` - The columns to request, or none to request every column.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the rows decoded into `T`, leaving out IDs that don't exist, or an
+    /// `Error` if a request to XIVAPI fails.
+    pub async fn sheet_rows_by_ids<T: DeserializeOwned>(
+        &self,
+        sheet: &str,
+        ids: &[u32],
+        columns: &[impl AsRef<str>],
+    ) -> Result<Vec<T>, Error> {
+        let mut rows = Vec::with_capacity(ids.len());
+
+        for chunk in ids.chunks(MAX_IDS) {
+            let page = SheetRowsRequest::new(sheet)
+                .ids(chunk.iter().copied())
+                .limit(chunk.len() as u32)
+                .columns(columns.iter().map(AsRef::as_ref))
+                .send::<T>(self)
+                .await?;
+
+            rows.extend(page.results);
+        }
+
+        Ok(rows)
+    }
+
+    /// Gets a single row of a game data sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The name of the sheet, e.g. `Item`.
+    /// * `id` - The ID of the row.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the row decoded into `T`, e.g. `serde_json::Value`, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn sheet_row<T: DeserializeOwned>(&self, sheet: &str, id: u32) -> Result<T, Error> {
+        SheetRowRequest::new(sheet, id).send(self).await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde::Deserialize;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::SheetPage;
+    use crate::{request::SheetRowsRequest, Error, XIVAPIClient};
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Mount {
+        #[serde(rename = "ID")]
+        id: u32,
+        name: String,
+    }
+
+    /// Responds with a page of sheet rows, given as a JSON array.
+    pub(crate) fn sheet_page(page: u32, page_total: u32, results: &str) -> ResponseTemplate {
+        let results: serde_json::Value = serde_json::from_str(results).unwrap();
+        let count = results.as_array().map_or(0, Vec::len) as u32;
+
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Pagination": {
+                "Page": page,
+                "PageNext": (page < page_total).then_some(page + 1),
+                "PagePrev": (page > 1).then(|| page - 1),
+                "PageTotal": page_total,
+                "Results": count,
+                "ResultsPerPage": count,
+                "ResultsTotal": count * page_total,
+            },
+            "Results": results,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_sheets() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"["Item", "Mount"]"#))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/Mount"))
+            .and(query_param("ids", "1,4"))
+            .respond_with(sheet_page(
+                1,
+                1,
+                r#"[{"ID": 1, "Name": "Company Chocobo"}, {"ID": 4, "Name": "Unicorn"}]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/Mount/4"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"ID": 4, "Name": "Unicorn"}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        assert_eq!(client.content_list().await?, ["Item", "Mount"]);
+
+        let page: SheetPage<Mount> = SheetRowsRequest::new("Mount")
+            .ids([1, 4])
+            .send(&client)
+            .await?;
+        assert_eq!(page.results[1].name, "Unicorn");
+
+        let row: serde_json::Value = client.sheet_row("Mount", 4).await?;
+        assert_eq!(row["Name"], "Unicorn");
+
+        let mount: Mount = client.sheet_row("Mount", 4).await?;
+        assert_eq!(mount.id, 4);
+
+        Ok(())
+    }
+}
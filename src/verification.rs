@@ -0,0 +1,408 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::{
+    character::{CharacterResult, LookupState},
+    error, Error, XIVAPIClient,
+};
+
+/// The characters tokens are made of unless configured otherwise. Leaves out look-alikes such
+/// as `0` and `O`, since users often type the token by hand.
+const DEFAULT_ALPHABET: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// When tokens with an expiry too long to represent expire: the end of the year 9999.
+const FAR_FUTURE: Duration = Duration::from_secs(253_402_300_799);
+
+/// The shape of the tokens a `Verifier` generates.
+///
+/// # Examples
+/// ```
+/// use xivapi_rust::verification::TokenFormat;
+///
+/// let format = TokenFormat::new().prefix("mybot-").length(8);
+/// let token = format.generate();
+///
+/// assert!(token.starts_with("mybot-"));
+/// assert_eq!(token.len(), 14);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TokenFormat {
+    prefix: String,
+    length: usize,
+    alphabet: Vec<char>,
+}
+
+impl TokenFormat {
+    /// Creates the default format: `XIV-` followed by 10 random upper case letters and digits.
+    pub fn new() -> Self {
+        TokenFormat {
+            prefix: "XIV-".to_string(),
+            length: 10,
+            alphabet: DEFAULT_ALPHABET.chars().collect(),
+        }
+    }
+
+    /// Sets the text every token starts with.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the number of random characters after the prefix. At least 1.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length.max(1);
+        self
+    }
+
+    /// Sets the characters the random part is made of. Ignored if empty.
+    pub fn alphabet(mut self, alphabet: &str) -> Self {
+        if !alphabet.is_empty() {
+            self.alphabet = alphabet.chars().collect();
+        }
+        self
+    }
+
+    /// Generates a new random token.
+    pub fn generate(&self) -> String {
+        let mut rng = rand::thread_rng();
+        let random = (0..self.length).map(|_| {
+            let index = rng.gen_range(0..self.alphabet.len());
+            self.alphabet[index]
+        });
+
+        self.prefix.chars().chain(random).collect()
+    }
+}
+
+impl Default for TokenFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a `Verifier` reads a character's bio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationMethod {
+    /// Asks XIVAPI's `/character/{id}/verification` endpoint, which reads the bio from the Lodestone.
+    Endpoint,
+    /// Looks the character up, bypassing the cache, and searches its bio.
+    ///
+    /// XIVAPI only sees the new bio once the character has been parsed again, so call
+    /// `XIVAPIClient::request_character_update()` first.
+    Lookup,
+}
+
+/// A token issued to a user, who proves they own a character by adding it to its Lodestone bio.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationToken {
+    /// The token the user has to add to their bio.
+    pub token: String,
+    /// The ID of the character being verified.
+    pub character_id: u32,
+    /// When the token was issued.
+    pub issued_at: SystemTime,
+    /// When the token stops being accepted.
+    pub expires_at: SystemTime,
+}
+
+impl VerificationToken {
+    /// Returns true if the token can no longer be used.
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at
+    }
+}
+
+/// Why a character couldn't be verified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationFailure {
+    /// The token expired before it was checked.
+    Expired,
+    /// The token isn't in the character's bio.
+    TokenNotFound,
+    /// The character doesn't exist.
+    CharacterNotFound,
+    /// The character's Lodestone profile is private.
+    ProfilePrivate,
+    /// XIVAPI is still adding the character, so its bio isn't available yet.
+    ProfilePending,
+    /// The character's owner asked XIVAPI not to serve their character.
+    Blacklisted,
+}
+
+/// The outcome of checking a `VerificationToken`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// The token is in the character's bio.
+    Verified,
+    /// The character couldn't be verified.
+    Failed(VerificationFailure),
+}
+
+impl Verification {
+    /// Returns true if the character was verified.
+    pub fn is_verified(&self) -> bool {
+        *self == Verification::Verified
+    }
+}
+
+/// Issues and checks tokens used to prove ownership of a character.
+///
+/// # Examples
+/// ```no_run
+/// use std::time::Duration;
+/// use xivapi_rust::{verification::Verifier, XIVAPIClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), xivapi_rust::Error> {
+///     let client = XIVAPIClient::new();
+///     let verifier = Verifier::new().expiry(Duration::from_secs(15 * 60));
+///
+///     let token = verifier.issue(12345678);
+///     println!("Add {} to your Lodestone bio", token.token);
+///
+///     // Once the user says they're done...
+///     let verification = verifier.verify(&client, &token).await?;
+///     println!("verified: {}", verification.is_verified());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    format: TokenFormat,
+    expiry: Duration,
+    method: VerificationMethod,
+}
+
+impl Verifier {
+    /// Creates a verifier using the default `TokenFormat`, a 30 minute expiry and XIVAPI's
+    /// verification endpoint.
+    pub fn new() -> Self {
+        Verifier {
+            format: TokenFormat::new(),
+            expiry: Duration::from_secs(30 * 60),
+            method: VerificationMethod::Endpoint,
+        }
+    }
+
+    /// Sets the format of the tokens generated.
+    pub fn format(mut self, format: TokenFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets how long tokens are valid for after being issued.
+    ///
+    /// Tokens with an expiry too long to represent expire at the end of the year 9999.
+    pub fn expiry(mut self, expiry: Duration) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Sets how the character's bio is read.
+    pub fn method(mut self, method: VerificationMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Issues a new token for a character.
+    pub fn issue(&self, character_id: u32) -> VerificationToken {
+        let issued_at = SystemTime::now();
+
+        VerificationToken {
+            token: self.format.generate(),
+            character_id,
+            issued_at,
+            expires_at: issued_at
+                .checked_add(self.expiry)
+                .unwrap_or(UNIX_EPOCH + FAR_FUTURE),
+        }
+    }
+
+    /// Checks whether a token is in its character's bio.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Verification` outcome, or an `Error` if the request to XIVAPI
+    /// fails for a reason unrelated to the character.
+    pub async fn verify(
+        &self,
+        client: &XIVAPIClient,
+        token: &VerificationToken,
+    ) -> Result<Verification, Error> {
+        if token.is_expired() {
+            return Ok(Verification::Failed(VerificationFailure::Expired));
+        }
+
+        let id = token.character_id.to_string();
+        let url = match self.method {
+            VerificationMethod::Endpoint => client
+                .url(&["character", &id, "verification"])
+                .param("token", &token.token)
+                .build(),
+            VerificationMethod::Lookup => client.url(&["character", &id]).build(),
+        };
+
+        let response = match client.fetch_uncached(&url).await {
+            Ok(response) => response,
+            Err(error) => {
+                return match LookupState::from_error(&error) {
+                    Some(state) => Ok(Verification::Failed(failure(state))),
+                    None => Err(error),
+                }
+            }
+        };
+
+        let state = LookupState::from_response(response.status, &response.body);
+
+        if state != LookupState::Ready {
+            return Ok(Verification::Failed(failure(state)));
+        }
+
+        let found = match self.method {
+            VerificationMethod::Endpoint => {
+                let result: VerificationResponse =
+                    error::decode(response.status, &url, &response.body)?;
+
+                result.pass.unwrap_or_else(|| {
+                    result
+                        .bio
+                        .is_some_and(|bio| bio.contains(token.token.as_str()))
+                })
+            }
+            VerificationMethod::Lookup => {
+                let result: CharacterResult = error::decode(response.status, &url, &response.body)?;
+
+                result.character.bio.contains(token.token.as_str())
+            }
+        };
+
+        if found {
+            Ok(Verification::Verified)
+        } else {
+            Ok(Verification::Failed(VerificationFailure::TokenNotFound))
+        }
+    }
+}
+
+impl Default for Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The response of XIVAPI's verification endpoint.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VerificationResponse {
+    bio: Option<String>,
+    pass: Option<bool>,
+}
+
+/// The reason a character in the given state can't be verified.
+///
+/// A character that is ready can be looked up, so only a missing token fails its verification.
+fn failure(state: LookupState) -> VerificationFailure {
+    match state {
+        LookupState::NotFound => VerificationFailure::CharacterNotFound,
+        LookupState::Private => VerificationFailure::ProfilePrivate,
+        LookupState::Blacklisted => VerificationFailure::Blacklisted,
+        LookupState::Pending => VerificationFailure::ProfilePending,
+        LookupState::Ready => VerificationFailure::TokenNotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{
+        failure, TokenFormat, Verification, VerificationFailure, VerificationMethod, Verifier,
+    };
+    use crate::{character::LookupState, Error, XIVAPIClient};
+
+    #[test]
+    fn test_token_format() {
+        let token = TokenFormat::new()
+            .prefix("")
+            .length(32)
+            .alphabet("ab")
+            .generate();
+
+        assert_eq!(token.len(), 32);
+        assert!(token.chars().all(|c| c == 'a' || c == 'b'));
+    }
+
+    #[test]
+    fn test_failure() {
+        assert_eq!(
+            failure(LookupState::Ready),
+            VerificationFailure::TokenNotFound
+        );
+        assert_eq!(
+            failure(LookupState::Pending),
+            VerificationFailure::ProfilePending
+        );
+        assert_eq!(
+            failure(LookupState::Private),
+            VerificationFailure::ProfilePrivate
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        let verifier = Verifier::new();
+        let token = verifier.issue(12345678);
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678/verification"))
+            .and(query_param("token", token.token.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                r#"{{"ID": 12345678, "Bio": "Hello! {}"}}"#,
+                token.token
+            )))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/character/12345678"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/character.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = XIVAPIClient::builder().base_url(server.uri()).build()?;
+
+        assert!(verifier.verify(&client, &token).await?.is_verified());
+
+        assert_eq!(
+            verifier
+                .clone()
+                .method(VerificationMethod::Lookup)
+                .verify(&client, &token)
+                .await?,
+            Verification::Failed(VerificationFailure::TokenNotFound)
+        );
+
+        let lasting = Verifier::new().expiry(Duration::MAX).issue(12345678);
+        assert!(!lasting.is_expired());
+
+        let expired = Verifier::new().expiry(Duration::ZERO).issue(12345678);
+        assert_eq!(
+            verifier.verify(&client, &expired).await?,
+            Verification::Failed(VerificationFailure::Expired)
+        );
+
+        Ok(())
+    }
+}
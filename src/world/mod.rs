@@ -0,0 +1,248 @@
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{request::DataCentersRequest, Error, XIVAPIClient};
+
+/// The prefix XIVAPI uses to filter searches by data center instead of world, e.g. `_dc_Chaos`.
+pub const DATA_CENTER_PREFIX: &str = "_dc_";
+
+/// How long a failed request for the catalog is remembered before it is requested again.
+const CATALOG_RETRY: Duration = Duration::from_secs(60);
+
+/// The region a data center is located in.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// North America.
+    NorthAmerica,
+    /// Europe.
+    Europe,
+    /// Japan.
+    Japan,
+    /// Oceania.
+    Oceania,
+    /// China, served by a separate publisher.
+    China,
+    /// Korea, served by a separate publisher.
+    Korea,
+}
+
+impl Region {
+    /// Returns the region of a data center, if it is known.
+    pub fn of(data_center: &str) -> Option<Region> {
+        let region = match data_center {
+            "Aether" | "Crystal" | "Dynamis" | "Primal" => Region::NorthAmerica,
+            "Chaos" | "Light" | "Shadow" => Region::Europe,
+            "Elemental" | "Gaia" | "Mana" | "Meteor" => Region::Japan,
+            "Materia" => Region::Oceania,
+            "陆行鸟" | "莫古力" | "猫小胖" | "豆豆柴" => Region::China,
+            "한국" => Region::Korea,
+            _ => return None,
+        };
+
+        Some(region)
+    }
+}
+
+/// A data center and the worlds it hosts.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct DataCenter {
+    /// The name of the data center.
+    pub name: String,
+    /// The region the data center is located in, if it is known.
+    pub region: Option<Region>,
+    /// The names of the worlds on the data center.
+    pub worlds: Vec<String>,
+}
+
+impl DataCenter {
+    /// Returns the filter that searches every world on the data center, e.g. `_dc_Chaos`.
+    pub fn filter(&self) -> String {
+        format!("{}{}", DATA_CENTER_PREFIX, self.name)
+    }
+}
+
+/// A world, also called a server.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct World {
+    /// The name of the world.
+    pub name: String,
+    /// The name of the data center hosting the world.
+    pub data_center: String,
+    /// The region the world is located in, if it is known.
+    pub region: Option<Region>,
+}
+
+/// Converts the worlds of each data center, as XIVAPI returns them, sorted by name.
+pub(crate) fn data_centers(data_centers: BTreeMap<String, Vec<String>>) -> Vec<DataCenter> {
+    data_centers
+        .into_iter()
+        .map(|(name, worlds)| DataCenter {
+            region: Region::of(&name),
+            name,
+            worlds,
+        })
+        .collect()
+}
+
+/// Checks a `server` search filter against the catalog of data centers.
+///
+/// World names are accepted for world filters. Data center names are accepted with the
+/// `_dc_` prefix, or on their own for endpoints that are filtered by data center.
+pub(crate) fn check_server(
+    data_centers: &[DataCenter],
+    server: &str,
+    by_data_center: bool,
+) -> Result<(), Error> {
+    let is_data_center = |name: &str| {
+        data_centers
+            .iter()
+            .any(|data_center| data_center.name.eq_ignore_ascii_case(name))
+    };
+
+    let known = match server.strip_prefix(DATA_CENTER_PREFIX) {
+        Some(data_center) => is_data_center(data_center),
+        None if by_data_center => is_data_center(server),
+        None => data_centers
+            .iter()
+            .flat_map(|data_center| &data_center.worlds)
+            .any(|world| world.eq_ignore_ascii_case(server)),
+    };
+
+    if known {
+        Ok(())
+    } else {
+        Err(Error::UnknownServer(server.to_string()))
+    }
+}
+
+/// The catalog of data centers used to validate `server` filters, shared by clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct ServerCatalog {
+    state: Mutex<CatalogState>,
+}
+
+#[derive(Debug, Default)]
+enum CatalogState {
+    #[default]
+    Empty,
+    Loaded(Arc<Vec<DataCenter>>),
+    Failed {
+        retry_at: Instant,
+        reason: String,
+    },
+}
+
+impl XIVAPIClient {
+    /// Lists every data center and the worlds it hosts.
+    ///
+    /// Use `DataCentersRequest` to request them in another language than the client's.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the data centers as `Vec<DataCenter>`, sorted by name, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn data_centers(&self) -> Result<Vec<DataCenter>, Error> {
+        DataCentersRequest::new().send(self).await
+    }
+
+    /// Lists every world, along with its data center.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the worlds as `Vec<World>`, grouped by data center, or an `Error`
+    /// if the request to XIVAPI fails.
+    pub async fn servers(&self) -> Result<Vec<World>, Error> {
+        Ok(self
+            .data_centers()
+            .await?
+            .into_iter()
+            .flat_map(|data_center| {
+                data_center.worlds.into_iter().map(move |name| World {
+                    name,
+                    data_center: data_center.name.clone(),
+                    region: data_center.region,
+                })
+            })
+            .collect())
+    }
+
+    /// Checks a `server` search filter before it is sent, if server validation is enabled.
+    ///
+    /// The catalog of data centers is requested once per client. If it can't be requested, the
+    /// search fails, and the catalog isn't requested again until `CATALOG_RETRY` has passed.
+    pub(crate) async fn validate_server(
+        &self,
+        server: Option<&str>,
+        by_data_center: bool,
+    ) -> Result<(), Error> {
+        let (Some(server), Some(catalog)) = (server, &self.servers) else {
+            return Ok(());
+        };
+
+        let data_centers = self.server_catalog(catalog).await?;
+
+        check_server(&data_centers, server, by_data_center)
+    }
+
+    async fn server_catalog(&self, catalog: &ServerCatalog) -> Result<Arc<Vec<DataCenter>>, Error> {
+        let mut state = catalog.state.lock().await;
+
+        match &*state {
+            CatalogState::Loaded(data_centers) => return Ok(Arc::clone(data_centers)),
+            CatalogState::Failed { retry_at, reason } if Instant::now() < *retry_at => {
+                return Err(Error::ServerCatalogUnavailable(reason.clone()));
+            }
+            _ => {}
+        }
+
+        match self.data_centers().await {
+            Ok(data_centers) => {
+                let data_centers = Arc::new(data_centers);
+                *state = CatalogState::Loaded(Arc::clone(&data_centers));
+                Ok(data_centers)
+            }
+            Err(error) => {
+                *state = CatalogState::Failed {
+                    retry_at: Instant::now() + CATALOG_RETRY,
+                    reason: error.to_string(),
+                };
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_server, DataCenter, Region};
+    use crate::Error;
+
+    fn catalog() -> Vec<DataCenter> {
+        vec![DataCenter {
+            name: "Chaos".to_string(),
+            region: Region::of("Chaos"),
+            worlds: vec!["Omega".to_string(), "Ragnarok".to_string()],
+        }]
+    }
+
+    #[test]
+    fn test_check_server() {
+        let catalog = catalog();
+
+        assert_eq!(catalog[0].region, Some(Region::Europe));
+        assert!(check_server(&catalog, "omega", false).is_ok());
+        assert!(check_server(&catalog, "_dc_Chaos", false).is_ok());
+        assert!(check_server(&catalog, "Chaos", true).is_ok());
+        assert!(matches!(
+            check_server(&catalog, "Omegaa", false),
+            Err(Error::UnknownServer(name)) if name == "Omegaa"
+        ));
+        assert!(check_server(&catalog, "Chaos", false).is_err());
+    }
+}